@@ -1,14 +1,23 @@
+use crate::internal::diff;
+use crate::internal::executor;
 use crate::internal::int_bounds;
+use crate::reporters;
+use crate::runner::Failure;
 pub use crate::runner::MonkeyResult;
+use crate::runner::NewMemo;
+use crate::BlockOn;
 use crate::BoxGen;
+use crate::BoxReporter;
 use crate::BoxShrink;
+use crate::ExampleFormatter;
 use crate::ExampleSize;
 use crate::Property;
 use crate::Seed;
 use rand::RngCore;
 use rand::SeedableRng;
-use std::fmt::Write;
 use std::sync::mpsc;
+use std::time::Duration;
+use std::time::Instant;
 
 /// Configuration for executing monkey tests.
 ///
@@ -22,6 +31,20 @@ pub struct Conf {
     pub seed: Seed,
     /// See [Conf::with_example_size].
     pub size: ExampleSize,
+    /// See [Conf::with_reporter].
+    pub reporter: BoxReporter,
+    /// See [Conf::with_block_on].
+    pub block_on: BlockOn,
+    /// See [Conf::with_process_isolation].
+    pub process_isolation: bool,
+    /// See [Conf::with_failure_reruns].
+    pub failure_reruns: u32,
+    /// See [Conf::with_shrink_backtracking].
+    pub shrink_backtracking_depth: usize,
+    /// See [Conf::with_shrink_backtracking].
+    pub shrink_backtracking_width: usize,
+    /// See [Conf::with_example_timeout].
+    pub example_timeout: Option<Duration>,
 }
 
 /// Configuration for executing monkey tests, including the generator.
@@ -36,6 +59,10 @@ where
     pub generator: BoxGen<E>,
     /// See [ConfAndGen::title].
     pub title: Option<String>,
+    /// See [ConfAndGen::with_formatter].
+    pub formatter: Option<ExampleFormatter<E>>,
+    /// See [ConfAndGen::with_shrink_memoization].
+    pub(crate) memo: Option<NewMemo<E>>,
 }
 
 impl Conf {
@@ -48,6 +75,8 @@ impl Conf {
             conf: self.clone(),
             generator,
             title: None,
+            formatter: None,
+            memo: None,
         }
     }
 
@@ -57,8 +86,7 @@ impl Conf {
     pub fn with_example_count(&self, example_count: u32) -> Conf {
         Self {
             example_count,
-            seed: self.seed,
-            size: self.size.clone(),
+            ..self.clone()
         }
     }
 
@@ -71,9 +99,8 @@ impl Conf {
         Size: std::ops::RangeBounds<usize>,
     {
         Self {
-            example_count: self.example_count,
-            seed: self.seed,
             size: int_bounds::to_inclusive_range(&size),
+            ..self.clone()
         }
     }
 
@@ -83,9 +110,152 @@ impl Conf {
     /// used in earlier test runs.
     pub fn with_seed(&self, seed: Seed) -> Conf {
         Self {
-            example_count: self.example_count,
             seed,
-            size: self.size.clone(),
+            ..self.clone()
+        }
+    }
+
+    /// Specify which reporter to use for progress and failure output. If not
+    /// specified, the silent [crate::reporters::quiet] reporter is used. See
+    /// module [crate::reporters] for other built in reporters.
+    pub fn with_reporter(&self, reporter: BoxReporter) -> Conf {
+        Self {
+            reporter,
+            ..self.clone()
+        }
+    }
+
+    /// Specify which function to use for running async properties, like
+    /// [ConfAndGen::assert_true_async], to completion. If not specified, a
+    /// minimal built in single threaded executor is used, which is enough for
+    /// futures not relying on a specific async runtime.
+    ///
+    /// ```rust
+    /// use monkey_test::*;
+    ///
+    /// fn my_block_on(future: BoxFuture<()>) {
+    ///     // Delegate to the `block_on` of the async runtime of your choice.
+    ///     # let _ = future;
+    /// }
+    ///
+    /// let conf = monkey_test().with_block_on(my_block_on);
+    /// ```
+    pub fn with_block_on(&self, block_on: BlockOn) -> Conf {
+        Self {
+            block_on,
+            ..self.clone()
+        }
+    }
+
+    /// Evaluate each example in a separate child process. This way, crashes
+    /// that can not be caught as panics, like stack overflows, calls to
+    /// `std::process::abort` and memory corruption, are treated as failures
+    /// and shrunk like any other failure. Signals and exit codes of crashed
+    /// child processes are presented in the failure reason.
+    ///
+    /// The child process is the test executable itself, running only the
+    /// current test. The child is told which example to evaluate by the seed
+    /// and the path of shrink steps taken to the example, so examples need
+    /// not be serializable. This requires the test to be run by the standard
+    /// test harness.
+    ///
+    /// It also requires the generator and shrinker to give the same examples
+    /// and shrink candidates in the child process as in the test process, so
+    /// they should only depend on the seed, the size and the example being
+    /// shrunk. Built in generators remembering how recent examples were
+    /// generated, like [crate::gens::string::from_regex], also remember the
+    /// examples along the path in the child process.
+    ///
+    /// Starting a process for each example is slow, so consider using fewer
+    /// examples. With [Conf::with_example_timeout], the child process is
+    /// killed when timing out, so that also examples that never finish are
+    /// found.
+    ///
+    /// Each test may evaluate only one process isolated property, since the
+    /// child process evaluates the first one in the test. Code in the test
+    /// before the isolated property, including properties not isolated, is
+    /// run again in each child process.
+    ///
+    /// ```rust,no_run
+    /// use monkey_test::*;
+    ///
+    /// fn depth(n: u32) -> u32 {
+    ///     if n == 0 { 0 } else { 1 + depth(n - 1) }
+    /// }
+    ///
+    /// monkey_test()
+    ///     .with_process_isolation()
+    ///     .with_example_count(20)
+    ///     .with_generator(gens::u32::ranged(..1000))
+    ///     .assert_eq(|n| n, depth);
+    /// ```
+    pub fn with_process_isolation(&self) -> Conf {
+        Self {
+            process_isolation: true,
+            ..self.clone()
+        }
+    }
+
+    /// Specify how many times a failing example is re-run, to detect flaky
+    /// properties depending on timing or global state, and not only on the
+    /// example. If not specified, failing examples are not re-run.
+    ///
+    /// While shrinking, only candidates failing in all re-runs are accepted
+    /// as failures. The minimum failure is also re-run before reporting, and
+    /// the result is marked as
+    /// [flaky](crate::MonkeyResult#variant.MonkeyErr.field.flaky) if the
+    /// property sometimes passed.
+    pub fn with_failure_reruns(&self, failure_reruns: u32) -> Conf {
+        Self {
+            failure_reruns,
+            ..self.clone()
+        }
+    }
+
+    /// Let shrinking backtrack when stuck in a local minimum, where no
+    /// shrink candidate of the smallest failure found so far fails. If not
+    /// specified, shrinking stops in the first local minimum found.
+    ///
+    /// Backtracking is a beam search through passing candidates, looking for
+    /// failures among their candidates in turn. The search explores at most
+    /// `width` candidates on each level, down to `depth` levels below the
+    /// local minimum. When a failure is found, shrinking continues from it.
+    pub fn with_shrink_backtracking(&self, depth: usize, width: usize) -> Conf {
+        Self {
+            shrink_backtracking_depth: depth,
+            shrink_backtracking_width: width,
+            ..self.clone()
+        }
+    }
+
+    /// Specify the maximum time each example may take to evaluate. If not
+    /// specified, there is no time limit.
+    ///
+    /// An example not finishing in time is treated as a failure, and
+    /// shrinking then looks for the smallest example that still times out.
+    /// Since each timed out example takes at least the given time, shrinking
+    /// stops when 20 examples of a property have timed out. The failure
+    /// reason then tells that shrinking stopped early.
+    ///
+    /// An example evaluated in the test process itself can not be stopped,
+    /// so it is found to have timed out first when it finishes. To also find
+    /// examples that never finish, like examples stuck in an endless loop or
+    /// a deadlock, combine with [Conf::with_process_isolation]. The child
+    /// process evaluating an example is then killed when timing out.
+    ///
+    /// ```rust
+    /// use monkey_test::*;
+    /// use std::time::Duration;
+    ///
+    /// monkey_test()
+    ///     .with_example_timeout(Duration::from_secs(1))
+    ///     .with_generator(gens::u64::ranged(..1000))
+    ///     .assert_true(|n| (0..n).sum::<u64>() < 1_000_000);
+    /// ```
+    pub fn with_example_timeout(&self, timeout: Duration) -> Conf {
+        Self {
+            example_timeout: Some(timeout),
+            ..self.clone()
         }
     }
 }
@@ -126,6 +296,13 @@ impl Default for Conf {
             example_count: global_example_count(),
             seed: global_seed(),
             size: global_example_size(),
+            reporter: crate::reporters::quiet(),
+            block_on: executor::block_on,
+            process_isolation: false,
+            failure_reruns: 0,
+            shrink_backtracking_depth: 0,
+            shrink_backtracking_width: 0,
+            example_timeout: None,
         }
     }
 }
@@ -144,11 +321,11 @@ where
     {
         crate::runner::evaluate_property(
             self,
-            catch_panic(|example: E| {
+            catch_panic(self.conf.example_timeout, move |example: E| {
                 if prop(example) {
                     Ok(())
                 } else {
-                    Err("Expecting 'true' but got 'false'.".into())
+                    Err(String::from("Expecting 'true' but got 'false'."))
                 }
             }),
         )
@@ -158,26 +335,152 @@ where
     /// It panics on failure.
     #[track_caller]
     pub fn assert_true(&self, prop: Property<E>) -> &ConfAndGen<E> {
-        panic_on_err(self.test_true(prop));
+        panic_on_err(
+            self.test_true(prop),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
         self
     }
 
-    /// Check that the property do not panic for any generated example values.
+    /// Check that the async property returns true for all generated example
+    /// values. Each future is run to completion by the function given in
+    /// [Conf::with_block_on], or by a minimal built in executor.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_async<Fut>(&self, prop: fn(E) -> Fut) -> MonkeyResult<E>
+    where
+        Fut: std::future::Future<Output = bool> + 'static,
+    {
+        let block_on = self.conf.block_on;
+
+        crate::runner::evaluate_property(
+            self,
+            catch_panic(self.conf.example_timeout, move |example: E| {
+                if run_to_completion(block_on, prop(example)) {
+                    Ok(())
+                } else {
+                    Err(String::from("Expecting 'true' but got 'false'."))
+                }
+            }),
+        )
+    }
+
+    /// Check that the async property returns true for all generated example
+    /// values. Each future is run to completion by the function given in
+    /// [Conf::with_block_on], or by a minimal built in executor.
     /// It panics on failure.
     #[track_caller]
-    pub fn assert_no_panic(&self, prop: fn(E) -> ()) -> &ConfAndGen<E> {
-        panic_on_err(crate::runner::evaluate_property(
+    pub fn assert_true_async<Fut>(&self, prop: fn(E) -> Fut) -> &ConfAndGen<E>
+    where
+        Fut: std::future::Future<Output = bool> + 'static,
+    {
+        panic_on_err(
+            self.test_async(prop),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the property returns `Ok(())` for all generated example
+    /// values, where the `Err` value is the reason of a failure. This is the
+    /// most general form of property, which all other properties are built
+    /// upon. It returns a [`MonkeyResult`](MonkeyResult) to indicate success
+    /// or failure.
+    pub fn test_result(
+        &self,
+        prop: fn(E) -> Result<(), String>,
+    ) -> MonkeyResult<E> {
+        crate::runner::evaluate_property(
             self,
-            catch_panic(|example| {
+            catch_panic(self.conf.example_timeout, prop),
+        )
+    }
+
+    /// Check that the property returns `Ok(())` for all generated example
+    /// values, where the `Err` value is the reason of a failure.
+    /// It panics on failure.
+    #[track_caller]
+    pub fn assert_result(
+        &self,
+        prop: fn(E) -> Result<(), String>,
+    ) -> &ConfAndGen<E> {
+        panic_on_err(
+            self.test_result(prop),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the property do not panic for any generated example values.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_no_panic(&self, prop: fn(E) -> ()) -> MonkeyResult<E> {
+        crate::runner::evaluate_property(
+            self,
+            catch_panic(self.conf.example_timeout, move |example| {
                 prop(example);
-                Ok(())
+                Ok::<(), String>(())
             }),
-        ));
+        )
+    }
+
+    /// Check that the property do not panic for any generated example values.
+    /// It panics on failure.
+    #[track_caller]
+    pub fn assert_no_panic(&self, prop: fn(E) -> ()) -> &ConfAndGen<E> {
+        panic_on_err(
+            self.test_no_panic(prop),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
         self
     }
 
     /// Check that the two from example derived values, expected and actual,
     /// equals each other.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_eq<D>(
+        &self,
+        expected: fn(E) -> D,
+        actual: fn(E) -> D,
+    ) -> MonkeyResult<E>
+    where
+        D: std::fmt::Debug + PartialEq,
+    {
+        crate::runner::evaluate_property(
+            self,
+            catch_panic(self.conf.example_timeout, move |example: E| {
+                let a = actual(example.clone());
+                let e = expected(example);
+                if a == e {
+                    Ok(())
+                } else {
+                    Err(Failure {
+                        reason: format!(
+                            "Actual value should equal expected {e:?}, but \
+                            got {a:?}."
+                        ),
+                        explanation: diff::explain_difference(&e, &a),
+                        timed_out: false,
+                    })
+                }
+            }),
+        )
+    }
+
+    /// Check that the two from example derived values, expected and actual,
+    /// equals each other.
+    /// It panics on failure.
+    ///
+    /// If the values of the minimum failure are large enough to be
+    /// pretty-printed on several lines, the failure reason is followed by the
+    /// paths of the differing parts and a line based diff of the
+    /// pretty-printed values, see
+    /// [explanation](MonkeyResult#variant.MonkeyErr.field.explanation).
     #[track_caller]
     pub fn assert_eq<D>(
         &self,
@@ -187,25 +490,45 @@ where
     where
         D: std::fmt::Debug + PartialEq,
     {
-        panic_on_err(crate::runner::evaluate_property(
+        panic_on_err(
+            self.test_eq(expected, actual),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the two from example derived values, expected and actual,
+    /// do not equals each other.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_ne<D>(
+        &self,
+        expected: fn(E) -> D,
+        actual: fn(E) -> D,
+    ) -> MonkeyResult<E>
+    where
+        D: std::fmt::Debug + PartialEq,
+    {
+        crate::runner::evaluate_property(
             self,
-            catch_panic(|example: E| {
+            catch_panic(self.conf.example_timeout, move |example: E| {
                 let a = actual(example.clone());
                 let e = expected(example);
-                if a == e {
+                if a != e {
                     Ok(())
                 } else {
                     Err(format!(
-                        "Actual value should equal expected {e:?}, but got {a:?}."
+                        "Actual value should not equal expected {e:?}, but got {a:?}."
                     ))
                 }
             }),
-        ));
-        self
+        )
     }
 
     /// Check that the two from example derived values, expected and actual,
     /// do not equals each other.
+    /// It panics on failure.
     #[track_caller]
     pub fn assert_ne<D>(
         &self,
@@ -215,20 +538,337 @@ where
     where
         D: std::fmt::Debug + PartialEq,
     {
-        panic_on_err(crate::runner::evaluate_property(
+        panic_on_err(
+            self.test_ne(expected, actual),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the property returns `Ok` for all generated example values.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure, where the reason of a failure contains the returned error.
+    pub fn test_ok<T, Err>(
+        &self,
+        prop: fn(E) -> Result<T, Err>,
+    ) -> MonkeyResult<E>
+    where
+        Err: std::fmt::Debug,
+    {
+        crate::runner::evaluate_property(
+            self,
+            catch_panic(
+                self.conf.example_timeout,
+                move |example: E| match prop(example) {
+                    Ok(_) => Ok(()),
+                    Err(err) => {
+                        Err(format!("Expecting 'Ok' but got 'Err({err:?})'."))
+                    }
+                },
+            ),
+        )
+    }
+
+    /// Check that the property returns `Ok` for all generated example values.
+    /// It panics on failure.
+    #[track_caller]
+    pub fn assert_ok<T, Err>(
+        &self,
+        prop: fn(E) -> Result<T, Err>,
+    ) -> &ConfAndGen<E>
+    where
+        Err: std::fmt::Debug,
+    {
+        panic_on_err(
+            self.test_ok(prop),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the property returns `Err` for all generated example values.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_err<T, Err>(
+        &self,
+        prop: fn(E) -> Result<T, Err>,
+    ) -> MonkeyResult<E>
+    where
+        T: std::fmt::Debug,
+    {
+        crate::runner::evaluate_property(
             self,
-            catch_panic(|example: E| {
+            catch_panic(
+                self.conf.example_timeout,
+                move |example: E| match prop(example) {
+                    Ok(value) => {
+                        Err(format!("Expecting 'Err' but got 'Ok({value:?})'."))
+                    }
+                    Err(_) => Ok(()),
+                },
+            ),
+        )
+    }
+
+    /// Check that the property returns `Err` for all generated example values.
+    /// It panics on failure.
+    #[track_caller]
+    pub fn assert_err<T, Err>(
+        &self,
+        prop: fn(E) -> Result<T, Err>,
+    ) -> &ConfAndGen<E>
+    where
+        T: std::fmt::Debug,
+    {
+        panic_on_err(
+            self.test_err(prop),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the property panics for all generated example values.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_panics(&self, prop: fn(E) -> ()) -> MonkeyResult<E> {
+        let evaluate = evaluator(self.conf.example_timeout, move |example| {
+            prop(example);
+            Ok(())
+        });
+
+        crate::runner::evaluate_property(self, |example: E| {
+            match evaluate(example) {
+                Ok(_) => {
+                    Err(String::from("Expecting panic, but got no panic.")
+                        .into())
+                }
+                Err(Interruption::Panic(..)) => Ok(()),
+                Err(Interruption::Timeout(timeout)) => {
+                    Err(Failure::timeout(timeout))
+                }
+            }
+        })
+    }
+
+    /// Check that the property panics for all generated example values.
+    /// It panics on failure.
+    #[track_caller]
+    pub fn assert_panics(&self, prop: fn(E) -> ()) -> &ConfAndGen<E> {
+        panic_on_err(
+            self.test_panics(prop),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the property panics for all generated example values, with
+    /// a panic message containing the given expected message.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_panics_with_message(
+        &self,
+        prop: fn(E) -> (),
+        expected_message: &str,
+    ) -> MonkeyResult<E> {
+        let evaluate = evaluator(self.conf.example_timeout, move |example| {
+            prop(example);
+            Ok(())
+        });
+
+        crate::runner::evaluate_property(self, |example: E| {
+            match evaluate(example) {
+                Ok(_) => Err(format!(
+                    "Expecting panic with message containing \
+                    {expected_message:?}, but got no panic."
+                )
+                .into()),
+                Err(Interruption::Panic(message, _))
+                    if message.contains(expected_message) =>
+                {
+                    Ok(())
+                }
+                Err(Interruption::Panic(message, location)) => Err(format!(
+                    "Expecting panic with message containing \
+                    {expected_message:?}, but got panic {message:?} {location}."
+                )
+                .into()),
+                Err(Interruption::Timeout(timeout)) => {
+                    Err(Failure::timeout(timeout))
+                }
+            }
+        })
+    }
+
+    /// Check that the property panics for all generated example values, with
+    /// a panic message containing the given expected message.
+    /// It panics on failure.
+    #[track_caller]
+    pub fn assert_panics_with_message(
+        &self,
+        prop: fn(E) -> (),
+        expected_message: &str,
+    ) -> &ConfAndGen<E> {
+        panic_on_err(
+            self.test_panics_with_message(prop, expected_message),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the first from example derived value is less than the
+    /// second one.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_lt<D>(
+        &self,
+        lesser: fn(E) -> D,
+        greater: fn(E) -> D,
+    ) -> MonkeyResult<E>
+    where
+        D: std::fmt::Debug + PartialOrd,
+    {
+        crate::runner::evaluate_property(
+            self,
+            catch_panic(self.conf.example_timeout, move |example: E| {
+                let l = lesser(example.clone());
+                let g = greater(example);
+                if l < g {
+                    Ok(())
+                } else {
+                    Err(format!("Value {l:?} should be less than {g:?}."))
+                }
+            }),
+        )
+    }
+
+    /// Check that the first from example derived value is less than the
+    /// second one.
+    /// It panics on failure.
+    #[track_caller]
+    pub fn assert_lt<D>(
+        &self,
+        lesser: fn(E) -> D,
+        greater: fn(E) -> D,
+    ) -> &ConfAndGen<E>
+    where
+        D: std::fmt::Debug + PartialOrd,
+    {
+        panic_on_err(
+            self.test_lt(lesser, greater),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the first from example derived value is less than or equal
+    /// to the second one.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_le<D>(
+        &self,
+        lesser: fn(E) -> D,
+        greater: fn(E) -> D,
+    ) -> MonkeyResult<E>
+    where
+        D: std::fmt::Debug + PartialOrd,
+    {
+        crate::runner::evaluate_property(
+            self,
+            catch_panic(self.conf.example_timeout, move |example: E| {
+                let l = lesser(example.clone());
+                let g = greater(example);
+                if l <= g {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "Value {l:?} should be less than or equal to {g:?}."
+                    ))
+                }
+            }),
+        )
+    }
+
+    /// Check that the first from example derived value is less than or equal
+    /// to the second one.
+    /// It panics on failure.
+    #[track_caller]
+    pub fn assert_le<D>(
+        &self,
+        lesser: fn(E) -> D,
+        greater: fn(E) -> D,
+    ) -> &ConfAndGen<E>
+    where
+        D: std::fmt::Debug + PartialOrd,
+    {
+        panic_on_err(
+            self.test_le(lesser, greater),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
+        self
+    }
+
+    /// Check that the two from example derived float values, expected and
+    /// actual, differ at most by given tolerance. `NaN` is never regarded to
+    /// be approximately equal to anything.
+    /// It returns a [`MonkeyResult`](MonkeyResult) to indicate success or
+    /// failure.
+    pub fn test_approx_eq<F>(
+        &self,
+        expected: fn(E) -> F,
+        actual: fn(E) -> F,
+        tolerance: F,
+    ) -> MonkeyResult<E>
+    where
+        F: num_traits::Float + std::fmt::Debug,
+    {
+        let tolerance_text = format!("{tolerance:?}");
+        let tolerance = tolerance.to_f64().unwrap_or(f64::NAN);
+
+        crate::runner::evaluate_property(
+            self,
+            catch_panic(self.conf.example_timeout, move |example: E| {
                 let a = actual(example.clone());
                 let e = expected(example);
-                if a != e {
+                let is_within_tolerance =
+                    (a - e).abs().to_f64().is_some_and(|d| d <= tolerance);
+                if a == e || is_within_tolerance {
                     Ok(())
                 } else {
                     Err(format!(
-                        "Actual value should not equal expected {e:?}, but got {a:?}."
+                        "Actual value should approximately equal expected \
+                        {e:?} within tolerance {tolerance_text}, but got {a:?}."
                     ))
                 }
             }),
-        ));
+        )
+    }
+
+    /// Check that the two from example derived float values, expected and
+    /// actual, differ at most by given tolerance. `NaN` is never regarded to
+    /// be approximately equal to anything.
+    /// It panics on failure.
+    #[track_caller]
+    pub fn assert_approx_eq<F>(
+        &self,
+        expected: fn(E) -> F,
+        actual: fn(E) -> F,
+        tolerance: F,
+    ) -> &ConfAndGen<E>
+    where
+        F: num_traits::Float + std::fmt::Debug,
+    {
+        panic_on_err(
+            self.test_approx_eq(expected, actual, tolerance),
+            &self.formatter,
+            self.conf.reporter.as_ref(),
+        );
         self
     }
 
@@ -240,6 +880,21 @@ where
         }
     }
 
+    /// Add or change how failing examples are presented in the message of a
+    /// failed assert. The formatter is applied to the minimum failure, the
+    /// original failure and the other failures. If not specified, the `{:?}`
+    /// debug format is used. See module [crate::formatters] for built in
+    /// formatters.
+    pub fn with_formatter<F>(&self, formatter: F) -> ConfAndGen<E>
+    where
+        F: Fn(&E) -> String + 'static,
+    {
+        Self {
+            formatter: Some(std::rc::Rc::new(formatter)),
+            ..self.clone()
+        }
+    }
+
     /// Add or change title of all following asserts. The title is used for
     /// naming the failed property assert. The title is used on all following
     /// properties, until other title is set.
@@ -251,12 +906,54 @@ where
     }
 }
 
-/// Panics on an error MonkeyResult. The panic has a message that tries to
-/// present an easy interpretable test result of the property under test.
-fn panic_on_err<E>(result: MonkeyResult<E>)
+/// Configuration of shrink memoization, only available for example types that
+/// can be hashed and compared.
+impl<E> ConfAndGen<E>
 where
+    E: std::fmt::Debug
+        + std::panic::UnwindSafe
+        + Clone
+        + std::hash::Hash
+        + Eq
+        + 'static,
+{
+    /// Remember all examples tried while shrinking, for all following
+    /// asserts, so the same example is never evaluated twice. This is useful
+    /// when evaluating the property is slow, or when the shrinker produces
+    /// the same candidates repeatedly, at the cost of keeping all tried
+    /// examples in memory while shrinking.
+    ///
+    /// ```rust
+    /// use monkey_test::*;
+    ///
+    /// monkey_test()
+    ///     .with_generator(gens::vec::any(gens::u8::any()))
+    ///     .with_shrink_memoization()
+    ///     .assert_true(|v| v.len() < 2000);
+    /// ```
+    pub fn with_shrink_memoization(&self) -> ConfAndGen<E> {
+        Self {
+            memo: Some(crate::runner::new_memo),
+            ..self.clone()
+        }
+    }
+}
+
+/// Panics on an error MonkeyResult. The panic has a message that tries to
+/// present an easy interpretable test result of the property under test. The
+/// message is given by the reporter, see [crate::Reporter::assert_failed].
+fn panic_on_err<E>(
+    result: MonkeyResult<E>,
+    formatter: &Option<ExampleFormatter<E>>,
+    reporter: &dyn crate::Reporter,
+) where
     E: std::fmt::Debug,
 {
+    let format = |example: &E| match formatter {
+        Some(f) => f(example),
+        None => format!("{example:?}"),
+    };
+
     if let MonkeyResult::MonkeyErr {
         minimum_failure,
         seed,
@@ -265,80 +962,148 @@ where
         reason,
         some_other_failures,
         original_failure,
+        flaky,
+        explanation,
         ..
     } = result
     {
-        let first_line = match title {
-            Some(t) => format!("Monkey test property \"{t}\" failed!"),
-            None => "Monkey test property failed!".into(),
-        };
-
-        let other_failures_text: String = some_other_failures.iter().fold(
-            String::new(),
-            |mut output, failure| {
-                let _ = write!(output, "\n\t{failure:?}");
-                output
-            },
-        );
+        let other_failures = std::iter::once(&original_failure)
+            .chain(some_other_failures.iter())
+            .map(format)
+            .collect::<Vec<_>>();
+
+        let message = reporter.assert_failed(&reporters::FailureReport {
+            title: title.as_deref(),
+            minimum_failure: &format(&minimum_failure),
+            reason: &(reason + &explanation),
+            flaky,
+            seed,
+            success_count,
+            other_failures: &other_failures,
+        });
+
+        panic!("{message}")
+    }
+}
+
+/// Runs given future to completion using given `block_on` function, returning
+/// the output of the future.
+fn run_to_completion<O, Fut>(block_on: BlockOn, future: Fut) -> O
+where
+    O: 'static,
+    Fut: std::future::Future<Output = O> + 'static,
+{
+    let output = std::rc::Rc::new(std::cell::Cell::new(None));
+    let output_to_set = output.clone();
+
+    block_on(Box::pin(async move {
+        output_to_set.set(Some(future.await));
+    }));
+
+    output
+        .take()
+        .expect("block_on function should run future to completion")
+}
 
-        panic!(
-            "{first_line}\n\
-            Failure: {minimum_failure:?}\n\
-            Reason: {reason}\n\
-            \n\
-            Reproduction seed: {seed}\n\
-            Success count before failure: {success_count}\n\
-            Other failures:\n\t{original_failure:?}{other_failures_text}\n",
+/// Catches panics and timeouts and treats them as the same as a property
+/// failure.
+fn catch_panic<E, P, R>(
+    timeout: Option<Duration>,
+    prop: P,
+) -> impl Fn(E) -> Result<(), Failure>
+where
+    E: std::fmt::Debug + std::panic::UnwindSafe,
+    P: std::panic::RefUnwindSafe + Fn(E) -> Result<(), R>,
+    R: Into<Failure>,
+{
+    let evaluate =
+        evaluator(timeout, move |example| prop(example).map_err(Into::into));
+
+    move |example: E| match evaluate(example) {
+        Ok(inner_result) => inner_result,
+        Err(Interruption::Panic(message, location)) => Err(format!(
+            "Expecting no panic, but got panic {message:?} {location}."
         )
+        .into()),
+        Err(Interruption::Timeout(timeout)) => Err(Failure::timeout(timeout)),
+    }
+}
+
+/// Reason for a property not returning normally.
+enum Interruption {
+    /// The property panicked, with given panic message and location.
+    Panic(String, String),
+    /// The property did not finish within given timeout.
+    Timeout(Duration),
+}
+
+/// Evaluates examples with given property, catching any panic. If a timeout
+/// is given, examples running for longer than that are regarded as timed out.
+fn evaluator<E, P>(
+    timeout: Option<Duration>,
+    prop: P,
+) -> impl Fn(E) -> Result<Result<(), Failure>, Interruption>
+where
+    E: std::fmt::Debug + std::panic::UnwindSafe,
+    P: std::panic::RefUnwindSafe + Fn(E) -> Result<(), Failure>,
+{
+    move |example: E| {
+        let start = Instant::now();
+        let result =
+            run_catching_panic(|| std::panic::catch_unwind(|| prop(example)));
+
+        match timeout {
+            Some(timeout) if start.elapsed() > timeout => {
+                Err(Interruption::Timeout(timeout))
+            }
+            _ => result,
+        }
     }
 }
 
-/// Catches panics and treats a panic as the same as a property failure.
-fn catch_panic<E, P>(prop: P) -> impl Fn(E) -> Result<(), String>
+/// Runs given closure, catching any panic. On panic, the panic message and
+/// location is returned as error.
+fn run_catching_panic<F>(f: F) -> Result<Result<(), Failure>, Interruption>
 where
-    E: std::fmt::Debug + std::panic::UnwindSafe + Clone + 'static,
-    P: std::panic::RefUnwindSafe + Fn(E) -> Result<(), String>,
+    F: FnOnce() -> std::thread::Result<Result<(), Failure>>,
 {
     // Please Note! Since `std::panic::set_hook` is global, we might have a race
     // condition here if multiple threads are running tests in parallel. In that
     // case, panics from other threads might be caught here and hooks might
     // interfere with each other. Leaving as-is for now.
 
-    move |example: E| {
-        let original_panic_hook = std::panic::take_hook();
-        let (tx, rx) = mpsc::channel();
-
-        // Set temporary panic hook catching original panic loaction and
-        // muting stacktrace printouts to stdout. If not muted, there can be a panic
-        // stacktrace for each failing example while shrinking a failure.
-        std::panic::set_hook(Box::new(move |info| {
-            if let Some(loc) = info.location() {
-                let location_text =
-                    format! {"in file '{}' at line {}", loc.file(), loc.line()};
-
-                // Ignoring send errors, since there is nothing to do if
-                // receiver has hung up. We are then out of test scope anyway.
-                let _ = tx.send(location_text);
-            }
-        }));
-
-        // Do a test with a single example
-        let result_or_panic = std::panic::catch_unwind(|| prop(example));
-
-        // Restore old original panic hook
-        std::panic::set_hook(original_panic_hook);
-
-        match result_or_panic {
-            Ok(inner_result) => inner_result,
-            Err(panic) => {
-                let message =
-                    panic_message::get_panic_message(&panic).unwrap_or("<?>");
-                let location =
-                    rx.try_recv().unwrap_or("at unknown location".into());
-                Err(
-                    format! {"Expecting no panic, but got panic {message:?} {location}." },
-                )
-            }
+    let original_panic_hook = std::panic::take_hook();
+    let (tx, rx) = mpsc::channel();
+
+    // Set temporary panic hook catching original panic loaction and
+    // muting stacktrace printouts to stdout. If not muted, there can be a panic
+    // stacktrace for each failing example while shrinking a failure.
+    std::panic::set_hook(Box::new(move |info| {
+        if let Some(loc) = info.location() {
+            let location_text =
+                format! {"in file '{}' at line {}", loc.file(), loc.line()};
+
+            // Ignoring send errors, since there is nothing to do if
+            // receiver has hung up. We are then out of test scope anyway.
+            let _ = tx.send(location_text);
+        }
+    }));
+
+    // Do a test with a single example
+    let result_or_panic = f();
+
+    // Restore old original panic hook
+    std::panic::set_hook(original_panic_hook);
+
+    match result_or_panic {
+        Ok(result) => Ok(result),
+        Err(panic) => {
+            let message = panic_message::get_panic_message(&panic)
+                .unwrap_or("<?>")
+                .to_string();
+            let location =
+                rx.try_recv().unwrap_or("at unknown location".into());
+            Err(Interruption::Panic(message, location))
         }
     }
 }
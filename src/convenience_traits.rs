@@ -1,5 +1,5 @@
 //! Convenience traits for generator and shrinker combinators.
-use crate::{BoxGen, BoxShrink, Gen, Shrink};
+use crate::{BoxGen, BoxReporter, BoxShrink, Gen, Reporter, Shrink};
 
 mod filter;
 mod map;
@@ -52,3 +52,24 @@ impl<E: Clone> Clone for BoxShrink<E> {
         self.clone_box()
     }
 }
+
+/// Trait that enables cloning a boxed reporter.
+#[doc(hidden)]
+pub trait CloneReporter {
+    fn clone_box(&self) -> BoxReporter;
+}
+
+impl<T> CloneReporter for T
+where
+    T: Reporter + Clone + 'static,
+{
+    fn clone_box(&self) -> BoxReporter {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for BoxReporter {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
@@ -1,6 +1,6 @@
 //! Convenience traits for generator and shrinker combinators for zipping.
 
-use crate::{gens, shrinks, BoxGen, BoxShrink, Gen, MapWithGen, Shrink};
+use crate::{gens, shrinks, BoxGen, BoxShrink, Gen, Shrink};
 
 /// Not dyn compatible (a.k.a. object safe) trait for providing generator
 /// zipping.
@@ -17,7 +17,7 @@ where
     where
         E1: Clone + 'static;
 
-    /// Zip together 3 generators.
+    /// Zip together 3 generators. See [gens::zip_3].
     fn zip_3<E1, E2>(
         &self,
         gen1: BoxGen<E1>,
@@ -27,7 +27,7 @@ where
         E1: Clone + 'static,
         E2: Clone + 'static;
 
-    /// Zip together 4 generators.
+    /// Zip together 4 generators. See [gens::zip_4].
     fn zip_4<E1, E2, E3>(
         &self,
         gen1: BoxGen<E1>,
@@ -39,7 +39,7 @@ where
         E2: Clone + 'static,
         E3: Clone + 'static;
 
-    /// Zip together 5 generators.
+    /// Zip together 5 generators. See [gens::zip_5].
     fn zip_5<E1, E2, E3, E4>(
         &self,
         gen1: BoxGen<E1>,
@@ -53,7 +53,7 @@ where
         E3: Clone + 'static,
         E4: Clone + 'static;
 
-    /// Zip together 6 generators.
+    /// Zip together 6 generators. See [gens::zip_6].
     fn zip_6<E1, E2, E3, E4, E5>(
         &self,
         gen1: BoxGen<E1>,
@@ -87,8 +87,7 @@ impl<E0: Clone + 'static> ZipWithGen<E0> for dyn Gen<E0> {
         E1: Clone + 'static,
         E2: Clone + 'static,
     {
-        gens::zip(gens::zip(self.clone_box(), gen1), gen2)
-            .map(|((e0, e1), e2)| (e0, e1, e2), |(e0, e1, e2)| ((e0, e1), e2))
+        gens::zip_3(self.clone_box(), gen1, gen2)
     }
 
     fn zip_4<E1, E2, E3>(
@@ -102,10 +101,7 @@ impl<E0: Clone + 'static> ZipWithGen<E0> for dyn Gen<E0> {
         E2: Clone + 'static,
         E3: Clone + 'static,
     {
-        gens::zip(gens::zip(self.clone_box(), gen1), gens::zip(gen2, gen3)).map(
-            |((e0, e1), (e2, e3))| (e0, e1, e2, e3),
-            |(e0, e1, e2, e3)| ((e0, e1), (e2, e3)),
-        )
+        gens::zip_4(self.clone_box(), gen1, gen2, gen3)
     }
 
     fn zip_5<E1, E2, E3, E4>(
@@ -121,11 +117,7 @@ impl<E0: Clone + 'static> ZipWithGen<E0> for dyn Gen<E0> {
         E3: Clone + 'static,
         E4: Clone + 'static,
     {
-        gens::zip(gens::zip(self.clone_box(), gen1), gen2.zip_3(gen3, gen4))
-            .map(
-                |((e0, e1), (e2, e3, e4))| (e0, e1, e2, e3, e4),
-                |(e0, e1, e2, e3, e4)| ((e0, e1), (e2, e3, e4)),
-            )
+        gens::zip_5(self.clone_box(), gen1, gen2, gen3, gen4)
     }
 
     fn zip_6<E1, E2, E3, E4, E5>(
@@ -143,10 +135,7 @@ impl<E0: Clone + 'static> ZipWithGen<E0> for dyn Gen<E0> {
         E4: Clone + 'static,
         E5: Clone + 'static,
     {
-        gens::zip(self.zip_3(gen1, gen2), gen3.zip_3(gen4, gen5)).map(
-            |((e0, e1, e2), (e3, e4, e5))| (e0, e1, e2, e3, e4, e5),
-            |(e0, e1, e2, e3, e4, e5)| ((e0, e1, e2), (e3, e4, e5)),
-        )
+        gens::zip_6(self.clone_box(), gen1, gen2, gen3, gen4, gen5)
     }
 }
 
@@ -0,0 +1,210 @@
+//! The `formatters` module contains built in formatters, for presenting
+//! failing examples in a more readable way than the default `{:?}` debug
+//! format.
+//!
+//! A formatter is selected with [crate::ConfAndGen::with_formatter] and is
+//! applied to the minimum failure, the original failure and the other failures
+//! in the message of a failed assert.
+//!
+//! ```rust
+//! use monkey_test::*;
+//!
+//! monkey_test()
+//!     .with_generator(gens::vec::any(gens::u8::any()))
+//!     // Failing byte vectors are presented as a hex dump of limited length.
+//!     .with_formatter(formatters::truncated(400, formatters::hex()))
+//!     .assert_true(|bytes| bytes.len() <= 1000);
+//! ```
+
+use std::fmt::Debug;
+
+/// Formatter using the compact `{:?}` debug format. This is the default
+/// formatter.
+pub fn debug<E>() -> impl Fn(&E) -> String + Clone
+where
+    E: Debug,
+{
+    |example: &E| format!("{example:?}")
+}
+
+/// Formatter using the pretty-printed `{:#?}` debug format, spreading large
+/// structs and collections over several lines.
+pub fn pretty<E>() -> impl Fn(&E) -> String + Clone
+where
+    E: Debug,
+{
+    |example: &E| format!("{example:#?}")
+}
+
+/// Formatter presenting bytes as a hex dump, with 16 bytes per line followed
+/// by the printable ASCII characters of the line.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// let format = formatters::hex();
+///
+/// assert_eq!(
+///     format(&b"Hi!\n".to_vec()),
+///     "[4 bytes]\n00000000  48 69 21 0a  |Hi!.|"
+/// );
+/// ```
+pub fn hex<E>() -> impl Fn(&E) -> String + Clone
+where
+    E: AsRef<[u8]>,
+{
+    |example: &E| {
+        let bytes = example.as_ref();
+        let lines = bytes.chunks(16).enumerate().map(|(index, line)| {
+            let hex = line
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let ascii = line
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+
+            format!("\n{:08x}  {hex}  |{ascii}|", index * 16)
+        });
+
+        format!("[{} bytes]", bytes.len()) + &lines.collect::<String>()
+    }
+}
+
+/// Formatter decorator, limiting the output of given formatter to at most
+/// `max_chars` characters. When the output is too long, the middle part is
+/// replaced by a note about the number of characters elided. If `max_chars`
+/// is too small to fit the note, the output is cut off after `max_chars`
+/// characters, without a note.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// let format = formatters::truncated(30, formatters::debug());
+///
+/// assert_eq!(
+///     format(&"abcdefghijklmnopqrstuvwxyz0123456789"),
+///     "\"abc...<31 chars elided>...89\""
+/// );
+/// ```
+pub fn truncated<E, F>(
+    max_chars: usize,
+    formatter: F,
+) -> impl Fn(&E) -> String + Clone
+where
+    F: Fn(&E) -> String + Clone,
+{
+    move |example: &E| {
+        let text = formatter(example);
+        let char_count = text.chars().count();
+
+        if char_count <= max_chars {
+            return text;
+        }
+
+        // The elided count is at most the char count, so a note sized for
+        // the char count always fits. The note for the resulting, smaller,
+        // elided count might be shorter, leaving room for more kept chars.
+        let note_size = |elided| elided_note(elided).chars().count();
+        let Some(kept_count) = max_chars.checked_sub(note_size(char_count))
+        else {
+            return text.chars().take(max_chars).collect();
+        };
+        let kept_count = max_chars - note_size(char_count - kept_count);
+
+        let head_count = kept_count.div_ceil(2);
+        let tail_count = kept_count / 2;
+        let elided_count = char_count - head_count - tail_count;
+
+        let head = text.chars().take(head_count).collect::<String>();
+        let tail = text.chars().skip(char_count - tail_count);
+
+        head + &elided_note(elided_count) + &tail.collect::<String>()
+    }
+}
+
+/// Note replacing the elided part of a truncated text.
+fn elided_note(elided_count: usize) -> String {
+    format!("...<{elided_count} chars elided>...")
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn pretty_formatter_spreads_struct_over_several_lines() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Point {
+            x: u8,
+            y: u8,
+        }
+
+        assert_eq!(
+            super::pretty()(&Point { x: 1, y: 2 }),
+            "Point {\n    x: 1,\n    y: 2,\n}"
+        );
+    }
+
+    #[test]
+    fn hex_formatter_splits_lines_every_sixteen_bytes() {
+        let bytes = (0u8..18).collect::<Vec<_>>();
+
+        assert_eq!(
+            super::hex()(&bytes),
+            "[18 bytes]\n\
+            00000000  00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f  \
+            |................|\n\
+            00000010  10 11  |..|"
+        );
+    }
+
+    #[test]
+    fn hex_formatter_handles_empty_input() {
+        assert_eq!(super::hex()(&Vec::<u8>::new()), "[0 bytes]");
+    }
+
+    #[test]
+    fn truncated_keeps_short_text_as_is() {
+        let format = super::truncated(5, super::debug());
+        assert_eq!(format(&12345), "12345");
+    }
+
+    #[test]
+    fn truncated_respects_multi_byte_chars() {
+        let format = super::truncated(26, |s: &String| s.clone());
+        assert_eq!(
+            format(&"åäöåäöåäöåäöåäöåäöåäöåäöåäöåäö".into()),
+            "åä...<27 chars elided>...ö"
+        );
+    }
+
+    #[test]
+    fn truncated_output_never_exceeds_max_chars() {
+        let text = "x".repeat(1000);
+        for max_chars in 0..1100 {
+            let format = super::truncated(max_chars, |s: &String| s.clone());
+            assert!(format(&text).chars().count() <= max_chars.min(1000));
+        }
+    }
+
+    #[test]
+    fn truncated_cuts_off_text_when_note_does_not_fit() {
+        let format = super::truncated(5, |s: &String| s.clone());
+        assert_eq!(format(&"abcdefghij".into()), "abcde");
+    }
+
+    #[test]
+    fn truncated_formatter_can_be_composed() {
+        let inner = super::truncated(30, super::hex());
+        let format = super::truncated(40, inner.clone());
+        assert_eq!(format(&vec![0u8; 100]), inner(&vec![0u8; 100]));
+    }
+}
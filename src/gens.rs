@@ -1,22 +1,29 @@
 //! The `gens` module contains built in generators.
 
 pub mod bool;
+pub mod bytes;
 mod chain;
 pub mod char;
+pub mod duration;
 mod filter;
 pub mod fixed;
 mod float;
-mod float_parts;
+pub(crate) mod float_parts;
 mod from_fn;
+pub mod grammar;
 mod integer;
-pub mod isize;
+mod isize_via_i64;
 mod map;
 mod mix;
+pub mod net;
 mod of_size;
+pub mod ordering;
 mod other_shrink;
+pub mod path;
 mod pick;
 pub mod sized;
 pub mod string;
+pub mod system_time;
 pub mod vec;
 mod zip;
 
@@ -27,13 +34,19 @@ pub use filter::filter;
 pub use from_fn::from_fn;
 pub use from_fn::from_fn_boxed;
 pub use map::map;
+pub use mix::frequency;
 pub use mix::mix_evenly;
 pub use mix::mix_with_ratio;
 pub use of_size::of_size;
 pub use other_shrink::other_shrinker;
 pub use pick::pick_evenly;
+pub use pick::pick_with_frequency;
 pub use pick::pick_with_ratio;
 pub use zip::zip;
+pub use zip::zip_3;
+pub use zip::zip_4;
+pub use zip::zip_5;
+pub use zip::zip_6;
 
 /// Standard way to generate seeds for random source.
 pub fn seeds() -> BoxGen<Seed> {
@@ -43,8 +56,12 @@ pub fn seeds() -> BoxGen<Seed> {
 /// Macro to generate code for all integer type modules
 macro_rules! integer_module {
     ($name:ident) => {
+        integer_module!($name, integer);
+    };
+    ($name:ident, $implementation:ident) => {
         /// Generators for values of module type.
         pub mod $name {
+            use crate::internal::sample_target::Ratio;
             use crate::BoxGen;
             use std::ops::RangeBounds;
 
@@ -60,7 +77,7 @@ macro_rules! integer_module {
             where
                 B: RangeBounds<$name>,
             {
-                super::integer::ranged(bounds)
+                super::$implementation::ranged(bounds)
             }
 
             /// Int generator with completely random distribution. This
@@ -69,7 +86,42 @@ macro_rules! integer_module {
             where
                 B: RangeBounds<$name>,
             {
-                super::integer::completely_random(bounds)
+                super::$implementation::completely_random(bounds)
+            }
+
+            /// Range of values mixing completely random values with bit
+            /// pattern edge cases, at given ratios. Edge cases are the
+            /// bounds, zero, powers of two and their negations, each ±1, and
+            /// all-ones patterns in the low and high bits, covering the
+            /// boundaries of all narrower integer types. Only edge cases in
+            /// given bounds are used.
+            ///
+            /// Panics if both ratios are zero.
+            ///
+            /// ```rust
+            /// use monkey_test::*;
+            ///
+            /// // Edge cases are used a fifth of the time.
+            /// let generator = gens::i64::edge_biased(.., 4, 1);
+            /// ```
+            pub fn edge_biased<B>(
+                bounds: B,
+                ratio_random: Ratio,
+                ratio_edge_cases: Ratio,
+            ) -> BoxGen<$name>
+            where
+                B: RangeBounds<$name>,
+            {
+                assert!(
+                    ratio_random > 0 || ratio_edge_cases > 0,
+                    "Given arguments ratio_random and ratio_edge_cases can \
+                    not both be zero."
+                );
+                super::$implementation::edge_biased(
+                    bounds,
+                    ratio_random,
+                    ratio_edge_cases,
+                )
             }
         }
     };
@@ -80,8 +132,10 @@ integer_module!(i16);
 integer_module!(i32);
 integer_module!(i64);
 integer_module!(i128);
-// Macro is not used for type isize, since not supported in underlying lib rand.
-// For details, see https://rust-random.github.io/book/update-0.9.html
+// Type isize is not supported in underlying lib rand, so it has its own
+// implementation. For details, see
+// https://rust-random.github.io/book/update-0.9.html
+integer_module!(isize, isize_via_i64);
 
 integer_module!(u8);
 integer_module!(u16);
@@ -90,6 +144,204 @@ integer_module!(u64);
 integer_module!(u128);
 integer_module!(usize);
 
+/// Macro to generate code for integer type modules of given wrapper type,
+/// like [std::num::Wrapping], piggybacking on the integer generators.
+macro_rules! wrapped_integer_module {
+    ($wrapper:ident, $name:ident) => {
+        /// Generators for wrapped values of module type.
+        pub mod $name {
+            use crate::BoxGen;
+            use crate::MapWithGen;
+            use std::num::$wrapper;
+            use std::ops::RangeBounds;
+
+            /// Roughly uniformly distributed unbound range of values, with
+            /// some overweight to extremes (min and max).
+            pub fn any() -> BoxGen<$wrapper<$name>> {
+                ranged(..)
+            }
+
+            /// Roughly uniformly distributed range of values, with some
+            /// overweight to extremes (min and max) of given bounds.
+            pub fn ranged<B>(bounds: B) -> BoxGen<$wrapper<$name>>
+            where
+                B: RangeBounds<$name>,
+            {
+                crate::gens::$name::ranged(bounds).map($wrapper, |w| w.0)
+            }
+
+            /// Int generator with completely random distribution. This
+            /// function has a long name, since `ranged` should be preferred.
+            pub fn completely_random<B>(bounds: B) -> BoxGen<$wrapper<$name>>
+            where
+                B: RangeBounds<$name>,
+            {
+                crate::gens::$name::completely_random(bounds)
+                    .map($wrapper, |w| w.0)
+            }
+        }
+    };
+}
+
+/// Generators for integers wrapped in [std::num::Wrapping]. Values shrink
+/// just like the wrapped integers.
+pub mod wrapping {
+    wrapped_integer_module!(Wrapping, i8);
+    wrapped_integer_module!(Wrapping, i16);
+    wrapped_integer_module!(Wrapping, i32);
+    wrapped_integer_module!(Wrapping, i64);
+    wrapped_integer_module!(Wrapping, i128);
+    wrapped_integer_module!(Wrapping, isize);
+    wrapped_integer_module!(Wrapping, u8);
+    wrapped_integer_module!(Wrapping, u16);
+    wrapped_integer_module!(Wrapping, u32);
+    wrapped_integer_module!(Wrapping, u64);
+    wrapped_integer_module!(Wrapping, u128);
+    wrapped_integer_module!(Wrapping, usize);
+}
+
+/// Generators for integers wrapped in [std::num::Saturating]. Values shrink
+/// just like the wrapped integers.
+pub mod saturating {
+    wrapped_integer_module!(Saturating, i8);
+    wrapped_integer_module!(Saturating, i16);
+    wrapped_integer_module!(Saturating, i32);
+    wrapped_integer_module!(Saturating, i64);
+    wrapped_integer_module!(Saturating, i128);
+    wrapped_integer_module!(Saturating, isize);
+    wrapped_integer_module!(Saturating, u8);
+    wrapped_integer_module!(Saturating, u16);
+    wrapped_integer_module!(Saturating, u32);
+    wrapped_integer_module!(Saturating, u64);
+    wrapped_integer_module!(Saturating, u128);
+    wrapped_integer_module!(Saturating, usize);
+}
+
+/// Macro to generate code for non-zero integer type modules, piggybacking on
+/// the integer generators for the negative and the positive values.
+macro_rules! nonzero_integer_module {
+    ($nonzero:ident, $name:ident) => {
+        /// Generators for non-zero values of module type.
+        pub mod $name {
+            use crate::BoxGen;
+            use crate::BoxShrink;
+            use crate::MapWithGen;
+            use std::num::$nonzero;
+            use std::ops::RangeBounds;
+            use std::ops::RangeInclusive;
+
+            /// Roughly uniformly distributed unbound range of values, with
+            /// some overweight to extremes (min and max).
+            pub fn any() -> BoxGen<$nonzero> {
+                ranged(..)
+            }
+
+            /// Roughly uniformly distributed range of values, with some
+            /// overweight to extremes (min and max) of given bounds. Zero is
+            /// never generated, not even when shrinking.
+            ///
+            /// # Panics
+            ///
+            /// Panics if zero is the only value in given bounds.
+            pub fn ranged<B>(bounds: B) -> BoxGen<$nonzero>
+            where
+                B: RangeBounds<$name>,
+            {
+                non_zero_parts_of(&bounds, crate::gens::$name::ranged)
+            }
+
+            /// Int generator with completely random distribution. This
+            /// function has a long name, since `ranged` should be preferred.
+            ///
+            /// # Panics
+            ///
+            /// Panics if zero is the only value in given bounds.
+            pub fn completely_random<B>(bounds: B) -> BoxGen<$nonzero>
+            where
+                B: RangeBounds<$name>,
+            {
+                non_zero_parts_of(
+                    &bounds,
+                    crate::gens::$name::completely_random,
+                )
+            }
+
+            /// Mix of generators for the negative and the positive part of
+            /// given bounds, weighted by the size of each part, so that zero
+            /// is never generated.
+            fn non_zero_parts_of<B>(
+                bounds: &B,
+                gen_in: fn(RangeInclusive<$name>) -> BoxGen<$name>,
+            ) -> BoxGen<$nonzero>
+            where
+                B: RangeBounds<$name>,
+            {
+                let (min, max) =
+                    crate::internal::int_bounds::to_inclusive_range_tuple(
+                        bounds,
+                    );
+                let zero: $name = 0;
+                let negatives = zero
+                    .checked_sub(1)
+                    .map(|minus_one| min..=max.min(minus_one));
+                let positives = Some(min.max(1)..=max);
+
+                let parts = [negatives, positives]
+                    .into_iter()
+                    .flatten()
+                    .filter(|part| !part.is_empty())
+                    .map(|part| {
+                        let size =
+                            *part.end() as f64 - *part.start() as f64 + 1.0;
+                        (size, gen_in(part))
+                    })
+                    .collect::<Vec<_>>();
+
+                if parts.is_empty() {
+                    panic!("Given bounds only contain zero.")
+                }
+
+                crate::gens::frequency(parts)
+                    .map(
+                        |i| $nonzero::new(i).expect("zero is never generated"),
+                        $nonzero::get,
+                    )
+                    .with_shrinker(toward_nearest_zero(min, max))
+            }
+
+            /// Shrink like integers in given range, skipping zero, so that
+            /// also negative values shrink toward the value one, if in range.
+            fn toward_nearest_zero(
+                min: $name,
+                max: $name,
+            ) -> BoxShrink<$nonzero> {
+                let ints = crate::shrinks::int_in_range(min, max);
+
+                crate::shrinks::from_fn(move |original: $nonzero| {
+                    ints.candidates(original.get()).filter_map($nonzero::new)
+                })
+            }
+        }
+    };
+}
+
+/// Generators for non-zero integers, like [std::num::NonZeroU32]. Values
+/// shrink like integers, toward the non-zero value nearest zero.
+pub mod nonzero {
+    nonzero_integer_module!(NonZeroI8, i8);
+    nonzero_integer_module!(NonZeroI16, i16);
+    nonzero_integer_module!(NonZeroI32, i32);
+    nonzero_integer_module!(NonZeroI64, i64);
+    nonzero_integer_module!(NonZeroI128, i128);
+    nonzero_integer_module!(NonZeroIsize, isize);
+    nonzero_integer_module!(NonZeroU8, u8);
+    nonzero_integer_module!(NonZeroU16, u16);
+    nonzero_integer_module!(NonZeroU32, u32);
+    nonzero_integer_module!(NonZeroU64, u64);
+    nonzero_integer_module!(NonZeroU128, u128);
+    nonzero_integer_module!(NonZeroUsize, usize);
+}
+
 /// Macro to generate code for all float type modules
 macro_rules! float_module {
     ($name:ident) => {
@@ -111,6 +363,9 @@ macro_rules! float_module {
             //! [ranged]  |     |   ✓              |       ✓          |      |
             //! [completely_random] | |     ✓      |       ✓          |      |
             //! [zero_to_one]|  |                  |        ✓         |      |
+            //! [subnormal]|    |   ✓              |       ✓          |      |
+            //! [nan]     |     |                  |                  |      |  ✓
+            //! [near]    |  ✓  |   ✓              |       ✓          |  ✓  |  ✓
             //!
             //!
             //! All generators but [completely_random] has some overweight
@@ -193,6 +448,43 @@ macro_rules! float_module {
             {
                 super::float::completely_random_range(bounds)
             }
+
+            /// Generator that only return subnormal numbers, being the finite
+            /// numbers closest to zero, with less precision than normal
+            /// numbers.
+            ///
+            /// Subnormals shrink toward smaller subnormals, never leaving the
+            /// subnormal numbers.
+            pub fn subnormal() -> BoxGen<$name> {
+                super::float::subnormal()
+            }
+
+            /// Generator that only return NaNs, with any sign bit and any
+            /// payload, both quiet and signalling ones.
+            ///
+            /// NaNs shrink toward the quiet, positive NaN without payload,
+            /// like `NAN`, never leaving the NaNs.
+            pub fn nan() -> BoxGen<$name> {
+                super::float::nan()
+            }
+
+            /// Generator that only return the given values and their closest
+            /// neighbours, one ULP (unit in the last place) below and above
+            /// each value.
+            ///
+            /// Examples shrink toward the given values, and toward values
+            /// given earlier.
+            ///
+            /// ```rust
+            /// use monkey_test::*;
+            ///
+            /// monkey_test()
+            ///     .with_generator(gens::f64::near(&[0.5, 1.5]))
+            ///     .assert_true(|x| x.round() != 0.5);
+            /// ```
+            pub fn near(values: &[$name]) -> BoxGen<$name> {
+                super::float::near(values)
+            }
         }
     };
 }
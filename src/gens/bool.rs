@@ -5,8 +5,11 @@ use crate::BoxGen;
 /// Generator of boolean values where ratio can be scewed according to given
 /// ratios.
 pub fn with_ratio(ratio_false: u8, ratio_true: u8) -> BoxGen<bool> {
-    crate::gens::pick_with_ratio(&[(ratio_false, false), (ratio_true, true)])
-        .with_shrinker(crate::shrinks::bool())
+    crate::gens::pick_with_ratio(&[
+        (ratio_false.into(), false),
+        (ratio_true.into(), true),
+    ])
+    .with_shrinker(crate::shrinks::bool())
 }
 
 /// Uniformly distributed generator of `true` and `false`.
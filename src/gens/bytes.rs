@@ -0,0 +1,288 @@
+//! Generators for byte vectors, for testing decoders of binary formats.
+//!
+//! All byte vectors shrink with [crate::shrinks::bytes], removing ranges of
+//! bytes and shrinking bytes toward zero, unless stated otherwise.
+
+use crate::BoxGen;
+use crate::MapWithGen;
+use rand::Rng;
+use rand::SeedableRng;
+
+/// Byte sequences that often have a special meaning in binary formats, like
+/// zero, sign bits and boundary lengths in different widths and byte orders.
+const MAGIC: &[&[u8]] = &[
+    &[0x00],
+    &[0xFF],
+    &[0x7F],
+    &[0x80],
+    &[0x00, 0x00],
+    &[0xFF, 0xFF],
+    &[0x00, 0xFF],
+    &[0xFF, 0x00],
+    &[0x01, 0x00],
+    &[0x00, 0x01],
+    &[0x7F, 0xFF],
+    &[0xFF, 0x7F],
+    &[0x80, 0x00],
+    &[0x00, 0x80],
+    &[0x00, 0x00, 0x00, 0x00],
+    &[0xFF, 0xFF, 0xFF, 0xFF],
+    &[0x7F, 0xFF, 0xFF, 0xFF],
+    &[0xFF, 0xFF, 0xFF, 0x7F],
+    &[0x80, 0x00, 0x00, 0x00],
+    &[0x00, 0x00, 0x00, 0x80],
+];
+
+/// Byte sequences that are never valid UTF-8, like lone continuation bytes,
+/// overlong encodings, encoded surrogates, truncated sequences and bytes that
+/// never occur in UTF-8.
+const INVALID_UTF8: &[&[u8]] = &[
+    &[0x80],
+    &[0xBF],
+    &[0xC0, 0x80],
+    &[0xC1, 0xBF],
+    &[0xE0, 0x80, 0x80],
+    &[0xED, 0xA0, 0x80],
+    &[0xF4, 0x90, 0x80, 0x80],
+    &[0xE2, 0x82],
+    &[0xF0, 0x9F, 0x98],
+    &[0xF5],
+    &[0xFE],
+    &[0xFF],
+];
+
+/// Any byte vector.
+pub fn any() -> BoxGen<Vec<u8>> {
+    crate::gens::vec::any(crate::gens::u8::any())
+        .with_shrinker(crate::shrinks::bytes())
+}
+
+/// Byte vectors of mostly printable ASCII characters, with some whitespace
+/// control characters and occasionally any other byte.
+pub fn mostly_ascii() -> BoxGen<Vec<u8>> {
+    crate::gens::vec::any(crate::gens::mix_with_ratio(&[
+        (90, crate::gens::u8::ranged(0x20..=0x7E)),
+        (8, crate::gens::pick_evenly(b"\t\n\r")),
+        (2, crate::gens::u8::any()),
+    ]))
+    .with_shrinker(crate::shrinks::bytes())
+}
+
+/// Byte vectors of valid UTF-8.
+///
+/// Byte vectors are shrunk as strings, see [crate::gens::string::unicode], so
+/// that shrunk byte vectors are also valid UTF-8.
+pub fn utf8() -> BoxGen<Vec<u8>> {
+    crate::gens::string::unicode().map(String::into_bytes, |bytes| {
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+/// Byte vectors of invalid UTF-8, being valid UTF-8 except for one
+/// invalid byte sequence, like an overlong encoding or a lone continuation
+/// byte.
+///
+/// Shrunk byte vectors are also invalid UTF-8.
+pub fn utf8_invalid() -> BoxGen<Vec<u8>> {
+    splice(utf8(), INVALID_UTF8, 1..=1).with_shrinker(
+        crate::shrinks::keep_only(
+            crate::shrinks::bytes(),
+            |bytes: &Vec<u8>| std::str::from_utf8(bytes).is_err(),
+        ),
+    )
+}
+
+/// Byte vectors from given generator, with one to three magic byte sequences
+/// spliced in at random positions. Magic byte sequences are values that often
+/// have a special meaning in binary formats, like 0x00, 0xFF, sign bits and
+/// boundary lengths in different widths and byte orders.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// monkey_test()
+///     .with_generator(gens::bytes::with_magic(gens::bytes::any()))
+///     .assert_true(|bytes| !bytes.is_empty());
+/// ```
+pub fn with_magic(bytes: BoxGen<Vec<u8>>) -> BoxGen<Vec<u8>> {
+    splice(bytes, MAGIC, 1..=3).with_shrinker(crate::shrinks::bytes())
+}
+
+/// Splice given number of the given byte sequences into the byte vectors of
+/// given generator, never in the middle of a UTF-8 encoded character.
+fn splice(
+    bytes: BoxGen<Vec<u8>>,
+    sequences: &'static [&'static [u8]],
+    count: std::ops::RangeInclusive<usize>,
+) -> BoxGen<Vec<u8>> {
+    crate::gens::from_fn(move |seed, size| {
+        let count = count.clone();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+
+        bytes.examples(seed, size).map(move |mut bytes| {
+            for _ in 0..rng.random_range(count.clone()) {
+                let positions = (0..=bytes.len())
+                    .filter(|&i| !is_continuation(bytes.get(i)))
+                    .collect::<Vec<_>>();
+                let at = positions[rng.random_range(0..positions.len())];
+                let sequence = sequences[rng.random_range(0..sequences.len())];
+                bytes.splice(at..at, sequence.iter().copied());
+            }
+            bytes
+        })
+    })
+}
+
+/// Tells if byte is a continuation byte of a UTF-8 encoded character.
+fn is_continuation(byte: Option<&u8>) -> bool {
+    byte.is_some_and(|b| b & 0xC0 == 0x80)
+}
+
+/// Encoding of the payload length, prefixed to frames by [length_prefixed].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthPrefix {
+    /// Length as a single byte.
+    U8,
+    /// Length as two bytes in big endian byte order.
+    U16Be,
+    /// Length as two bytes in little endian byte order.
+    U16Le,
+    /// Length as four bytes in big endian byte order.
+    U32Be,
+    /// Length as four bytes in little endian byte order.
+    U32Le,
+}
+
+impl LengthPrefix {
+    fn max_len(self) -> usize {
+        match self {
+            LengthPrefix::U8 => u8::MAX as usize,
+            LengthPrefix::U16Be | LengthPrefix::U16Le => u16::MAX as usize,
+            LengthPrefix::U32Be | LengthPrefix::U32Le => u32::MAX as usize,
+        }
+    }
+
+    fn encode(self, len: usize) -> Vec<u8> {
+        match self {
+            LengthPrefix::U8 => vec![len as u8],
+            LengthPrefix::U16Be => (len as u16).to_be_bytes().to_vec(),
+            LengthPrefix::U16Le => (len as u16).to_le_bytes().to_vec(),
+            LengthPrefix::U32Be => (len as u32).to_be_bytes().to_vec(),
+            LengthPrefix::U32Le => (len as u32).to_le_bytes().to_vec(),
+        }
+    }
+
+    /// Split frame into prefixed length and payload, if frame is long enough
+    /// to have a prefix.
+    fn split(self, frame: &[u8]) -> Option<(usize, &[u8])> {
+        let width = self.encode(0).len();
+        if frame.len() < width {
+            return None;
+        }
+        let (prefix, payload) = frame.split_at(width);
+        let len = match self {
+            LengthPrefix::U8 => prefix[0] as usize,
+            LengthPrefix::U16Be => {
+                u16::from_be_bytes([prefix[0], prefix[1]]) as usize
+            }
+            LengthPrefix::U16Le => {
+                u16::from_le_bytes([prefix[0], prefix[1]]) as usize
+            }
+            LengthPrefix::U32Be => {
+                u32::from_be_bytes(prefix.try_into().ok()?) as usize
+            }
+            LengthPrefix::U32Le => {
+                u32::from_le_bytes(prefix.try_into().ok()?) as usize
+            }
+        };
+        Some((len, payload))
+    }
+
+    fn frame(self, payload: Vec<u8>) -> Vec<u8> {
+        let mut payload = payload;
+        payload.truncate(self.max_len());
+        let mut frame = self.encode(payload.len());
+        frame.extend(payload);
+        frame
+    }
+}
+
+/// Frames of payloads from given generator, prefixed with the length of the
+/// payload. Payloads longer than the prefix can express are truncated.
+///
+/// Frames are shrunk by shrinking the payload with the shrinker of given
+/// generator, keeping the length prefix correct.
+///
+/// ```rust
+/// use monkey_test::*;
+/// use monkey_test::gens::bytes::LengthPrefix;
+///
+/// monkey_test()
+///     .with_generator(gens::bytes::length_prefixed(
+///         LengthPrefix::U16Be,
+///         gens::bytes::any(),
+///     ))
+///     .assert_true(|frame| {
+///         let len = u16::from_be_bytes([frame[0], frame[1]]) as usize;
+///         frame.len() == 2 + len
+///     });
+/// ```
+pub fn length_prefixed(
+    prefix: LengthPrefix,
+    payloads: BoxGen<Vec<u8>>,
+) -> BoxGen<Vec<u8>> {
+    let payload_shrinker = payloads.shrinker();
+
+    crate::gens::from_fn(move |seed, size| {
+        payloads
+            .examples(seed, size)
+            .map(move |payload| prefix.frame(payload))
+    })
+    .with_shrinker(crate::shrinks::from_fn(move |original: Vec<u8>| {
+        let payload = match prefix.split(&original) {
+            Some((len, payload)) if len == payload.len() => {
+                Some(payload.to_vec())
+            }
+            _ => None,
+        };
+
+        payload
+            .map(|payload| payload_shrinker.candidates(payload))
+            .into_iter()
+            .flatten()
+            .map(move |payload| prefix.frame(payload))
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::LengthPrefix;
+
+    #[test]
+    fn length_prefix_is_encoded_with_given_width_and_byte_order() {
+        let frame = |prefix: LengthPrefix| prefix.frame(vec![7; 258]);
+
+        assert_eq!(frame(LengthPrefix::U8)[..1], [255]);
+        assert_eq!(frame(LengthPrefix::U16Be)[..2], [1, 2]);
+        assert_eq!(frame(LengthPrefix::U16Le)[..2], [2, 1]);
+        assert_eq!(frame(LengthPrefix::U32Be)[..4], [0, 0, 1, 2]);
+        assert_eq!(frame(LengthPrefix::U32Le)[..4], [2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn payload_longer_than_prefix_can_express_is_truncated() {
+        let frame = LengthPrefix::U8.frame(vec![7; 300]);
+
+        assert_eq!(frame.len(), 256);
+        assert_eq!(LengthPrefix::U8.split(&frame).unwrap().0, 255);
+    }
+
+    #[test]
+    fn frames_too_short_for_prefix_can_not_be_split() {
+        assert_eq!(LengthPrefix::U32Be.split(&[0, 0, 1]), None);
+        assert_eq!(
+            LengthPrefix::U32Be.split(&[0, 0, 0, 1, 9]),
+            Some((1, &[9][..]))
+        );
+    }
+}
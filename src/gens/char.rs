@@ -11,6 +11,7 @@ pub fn unicode() -> BoxGen<char> {
     crate::gens::u32::ranged(0..=0x10ffff)
         .filter(|&num| !(0xD800..=0xDFFF).contains(&num))
         .map(|num| char::from_u32(num).unwrap(), |ch| ch as u32)
+        .with_shrinker(crate::shrinks::char())
 }
 
 /// Shorthand for [unicode].
@@ -18,10 +19,15 @@ pub fn any() -> BoxGen<char> {
     unicode()
 }
 
-/// Build char generator from u32 range (inclusive)
+/// Build char generator from u32 range (inclusive), shrinking toward the
+/// simplest char in range, see [crate::shrinks::char_in].
 fn chars_from_u32_range(min: u32, max_inclusive: u32) -> BoxGen<char> {
+    let range =
+        char::from_u32(min).unwrap()..=char::from_u32(max_inclusive).unwrap();
+
     crate::gens::u32::ranged(min..=max_inclusive)
         .map(|num| char::from_u32(num).unwrap(), |ch| ch as u32)
+        .with_shrinker(crate::shrinks::char_in(&[range]))
 }
 
 /// Any arabic numeral 0..9, unicode values 48..=57.
@@ -41,7 +47,7 @@ pub fn alpha_lower() -> BoxGen<char> {
 
 /// Any alpha char, both lower and upper case.
 pub fn alpha() -> BoxGen<char> {
-    crate::gens::mix_evenly(&[alpha_upper(), alpha_lower()])
+    crate::gens::mix_with_ratio(&[(1, alpha_upper()), (1, alpha_lower())])
 }
 
 /// Any alpha numeric char, see [alpha] and [number].
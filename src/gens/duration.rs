@@ -0,0 +1,52 @@
+//! Generators for values of type [std::time::Duration].
+
+use crate::BoxGen;
+use crate::MapWithGen;
+use std::ops::Bound;
+use std::ops::RangeBounds;
+use std::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Roughly uniformly distributed durations, with some overweight to zero and
+/// the longest possible duration. Durations shrink toward zero.
+pub fn any() -> BoxGen<Duration> {
+    ranged(..)
+}
+
+/// Roughly uniformly distributed durations in given range, with some
+/// overweight to the extremes of the range. Durations shrink toward the
+/// shortest duration in range.
+///
+/// ```rust
+/// use monkey_test::*;
+/// use std::time::Duration;
+///
+/// monkey_test()
+///     .with_generator(gens::duration::ranged(..Duration::from_secs(60)))
+///     .assert_true(|d| d.as_secs() < 60);
+/// ```
+pub fn ranged<B>(bounds: B) -> BoxGen<Duration>
+where
+    B: RangeBounds<Duration>,
+{
+    let start = match bounds.start_bound() {
+        Bound::Included(d) => Bound::Included(d.as_nanos()),
+        Bound::Excluded(d) => Bound::Excluded(d.as_nanos()),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(d) => Bound::Included(d.as_nanos()),
+        Bound::Excluded(d) => Bound::Excluded(d.as_nanos()),
+        Bound::Unbounded => Bound::Included(Duration::MAX.as_nanos()),
+    };
+
+    crate::gens::u128::ranged((start, end)).map(from_nanos, |d| d.as_nanos())
+}
+
+pub(crate) fn from_nanos(nanos: u128) -> Duration {
+    Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
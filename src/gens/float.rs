@@ -11,6 +11,7 @@ use rand::distr::uniform::SampleUniform;
 use rand::Rng;
 use rand::SeedableRng;
 use std::fmt::Debug;
+use std::num::FpCategory;
 use std::ops::Bound;
 use std::ops::RangeBounds;
 
@@ -20,8 +21,9 @@ pub fn any<F>() -> BoxGen<F>
 where
     F: Float + FloatParts + SampleUniform + 'static,
 {
-    let nans = gens::fixed::constant(F::nan());
-    gens::mix_with_ratio(&[(98, number()), (2, nans)])
+    let nans = gens::fixed::constant(F::nan())
+        .with_shrinker(crate::shrinks::float_and_nans());
+    gens::mix::mix_with_ratio_first_shrinking(&[(98, number()), (2, nans)])
 }
 
 /// Generator that only return finite numbers, `-Inf` and
@@ -30,8 +32,9 @@ pub fn number<F>() -> BoxGen<F>
 where
     F: Float + FloatParts + SampleUniform + 'static,
 {
-    let infs = gens::pick_evenly(&[F::neg_infinity(), F::infinity()]);
-    gens::mix_with_ratio(&[(98, finite()), (2, infs)])
+    let infs = gens::pick_evenly(&[F::neg_infinity(), F::infinity()])
+        .with_shrinker(crate::shrinks::float_and_nans());
+    gens::mix::mix_with_ratio_first_shrinking(&[(98, finite()), (2, infs)])
 }
 
 /// Generator that only return numbers between 0 and `+Inf`.
@@ -39,9 +42,10 @@ pub fn positive<F>() -> BoxGen<F>
 where
     F: Float + FloatParts + SampleUniform + 'static,
 {
-    let infs = gens::fixed::constant(F::infinity());
+    let infs = gens::fixed::constant(F::infinity())
+        .with_shrinker(crate::shrinks::float_and_nans());
     let finites = ranged(F::zero()..=F::max_value());
-    gens::mix_with_ratio(&[(98, finites), (2, infs)])
+    gens::mix::mix_with_ratio_first_shrinking(&[(98, finites), (2, infs)])
 }
 
 /// Generator that only return numbers between `-Inf` and -0.
@@ -49,9 +53,10 @@ pub fn negative<F>() -> BoxGen<F>
 where
     F: Float + FloatParts + SampleUniform + 'static,
 {
-    let infs = gens::fixed::constant(F::neg_infinity());
+    let infs = gens::fixed::constant(F::neg_infinity())
+        .with_shrinker(crate::shrinks::float_and_nans());
     let finites = ranged(F::min_value()..=F::neg_zero());
-    gens::mix_with_ratio(&[(98, finites), (2, infs)])
+    gens::mix::mix_with_ratio_first_shrinking(&[(98, finites), (2, infs)])
 }
 
 /// Generator that only return finite numbers between minimum
@@ -118,9 +123,10 @@ where
         .cloned()
         .collect::<Vec<_>>();
 
-    let special_values = gens::pick_evenly(&relevant_special_values);
+    let special_values = gens::pick_evenly(&relevant_special_values)
+        .with_shrinker(crate::shrinks::float_and_nans());
 
-    gens::mix_with_ratio(&[
+    gens::mix::mix_with_ratio_first_shrinking(&[
         (90, completely_random_range(bound)),
         (10, special_values),
     ])
@@ -154,7 +160,119 @@ where
         |i| from_twos_complement_bits(i),
         |f| to_twos_complement_bits(f),
     )
-    .with_shrinker(crate::shrinks::float())
+    .with_shrinker(crate::shrinks::float_and_nans())
+}
+
+/// Generator that only return subnormal numbers, being the finite numbers
+/// closest to zero, with less precision than normal numbers.
+///
+/// Subnormals shrink toward smaller subnormals, never leaving the subnormal
+/// numbers.
+pub fn subnormal<F>() -> BoxGen<F>
+where
+    F: Float + FloatParts + 'static,
+{
+    let randoms = gens::from_fn(|seed, _size| {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+        std::iter::from_fn(move || {
+            let fraction = rng.random_range(1..=F::fraction_max());
+            Some(F::from_bits(F::compose(rng.random(), 0, fraction)))
+        })
+    });
+
+    let min = F::from_bits(F::compose(false, 0, 1));
+    let max = F::from_bits(F::compose(false, 0, F::fraction_max()));
+    let extremes = gens::pick_evenly(&[min, min.neg(), max, max.neg()]);
+
+    gens::mix::mix_with_ratio_first_shrinking(&[(90, randoms), (10, extremes)])
+        .with_shrinker(crate::shrinks::keep_only(
+            crate::shrinks::float_and_nans(),
+            |f: &F| f.classify() == FpCategory::Subnormal,
+        ))
+}
+
+/// Generator that only return NaNs, with any sign bit and any payload, both
+/// quiet and signalling ones.
+///
+/// NaNs shrink toward the quiet, positive NaN without payload, like
+/// [f64::NAN], never leaving the NaNs.
+pub fn nan<F>() -> BoxGen<F>
+where
+    F: Float + FloatParts + 'static,
+{
+    let randoms = gens::from_fn(|seed, _size| {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+        std::iter::from_fn(move || {
+            let fraction = rng.random_range(1..=F::fraction_max());
+            Some(nan_from_parts(rng.random(), fraction))
+        })
+    });
+
+    let specials = gens::pick_evenly(&[
+        F::nan(),
+        F::nan().neg(),
+        nan_from_parts(false, 1),
+        nan_from_parts(true, F::fraction_max()),
+    ]);
+
+    gens::mix::mix_with_ratio_first_shrinking(&[(90, randoms), (10, specials)])
+        .with_shrinker(crate::shrinks::keep_only(
+            crate::shrinks::float_and_nans(),
+            |f: &F| f.is_nan(),
+        ))
+}
+
+fn nan_from_parts<F>(sign_is_negative: bool, fraction: u64) -> F
+where
+    F: Float + FloatParts,
+{
+    F::from_bits(F::compose(sign_is_negative, F::exponent_max(), fraction))
+}
+
+/// Generator that only return the given values and their closest neighbours,
+/// one ULP (unit in the last place) below and above each value.
+///
+/// Examples shrink toward the given values, and toward values given earlier.
+pub fn near<F>(values: &[F]) -> BoxGen<F>
+where
+    F: Float + FloatParts + 'static,
+{
+    let neighbourhoods = values
+        .iter()
+        .flat_map(|&v| [v, next_down(v), next_up(v)])
+        .collect::<Vec<_>>();
+
+    gens::pick_evenly(&neighbourhoods)
+}
+
+/// Closest value larger than given value. NaN and `Inf` have no larger
+/// value, and are returned as is.
+fn next_up<F>(f: F) -> F
+where
+    F: Float + FloatParts,
+{
+    if f.is_nan() || f == F::infinity() {
+        f
+    } else if f.is_zero() {
+        F::from_bits(F::compose(false, 0, 1))
+    } else {
+        from_twos_complement_bits(to_twos_complement_bits(f) + 1)
+    }
+}
+
+/// Closest value smaller than given value. NaN and `-Inf` have no smaller
+/// value, and are returned as is.
+fn next_down<F>(f: F) -> F
+where
+    F: Float + FloatParts,
+{
+    if f.is_nan() || f == F::neg_infinity() {
+        f
+    } else if f.is_zero() {
+        F::from_bits(F::compose(true, 0, 1))
+    } else {
+        from_twos_complement_bits(to_twos_complement_bits(f) - 1)
+    }
 }
 
 fn check_bounds_are_finite<F>(start: F, end: F)
@@ -426,6 +544,45 @@ mod test {
         gens::f32::completely_random(10.0..=f32::INFINITY);
     }
 
+    #[test]
+    fn verify_generator_subnormal() {
+        let generator = gens::f32::subnormal();
+        assert_all_values_are_in_range(
+            generator.clone(),
+            -f32::MIN_POSITIVE..=f32::MIN_POSITIVE,
+        );
+        assert_does_not_have_value(generator.clone(), 0.0);
+        assert_does_not_have_value(generator.clone(), -0.0);
+        assert_has_values(generator, &[f32::from_bits(1), -f32::from_bits(1)]);
+    }
+
+    #[test]
+    fn verify_generator_nan() {
+        let generator = gens::f32::nan();
+        assert!(generator
+            .examples(crate::global_seed(), crate::global_example_size())
+            .take(1000)
+            .all(f32::is_nan));
+    }
+
+    #[test]
+    fn next_up_and_down_are_one_ulp_away() {
+        use super::next_down;
+        use super::next_up;
+
+        assert_eq!(next_up(1.0f64), 1.0 + f64::EPSILON);
+        assert_eq!(next_down(1.0f64), 1.0 - f64::EPSILON / 2.0);
+        assert_eq!(next_up(-1.0f64), -1.0 + f64::EPSILON / 2.0);
+        assert_eq!(next_up(0.0f64), f64::from_bits(1));
+        assert_eq!(next_down(-0.0f64), -f64::from_bits(1));
+        assert_eq!(next_up(-f64::from_bits(1)), 0.0);
+        assert_eq!(next_up(f64::MAX), f64::INFINITY);
+        assert_eq!(next_up(f64::NEG_INFINITY), f64::MIN);
+        assert_eq!(next_up(f64::INFINITY), f64::INFINITY);
+        assert_eq!(next_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(next_up(f64::NAN).is_nan());
+    }
+
     #[test]
     fn should_have_shrinker() {
         assert_generator_can_shrink(gens::f64::any(), std::f64::consts::PI)
@@ -10,16 +10,27 @@ pub trait FloatParts: std::fmt::Debug {
     /// Max exponent value still being a finite (normal) number.
     fn exponent_normal_max() -> u16;
 
-    #[cfg(test)]
-    /// Max fraction value still being a finite (normal) number.
-    fn fraction_normal_max() -> u64;
-
     /// Number of bits to shift exponent.
     fn exponent_bit_position() -> u8;
 
     /// Number of bits to shift sign bit.
     fn sign_bit_position() -> u8;
 
+    /// Exponent of infinities and NaNs, with all exponent bits set.
+    fn exponent_max() -> u16 {
+        (1 << (Self::sign_bit_position() - Self::exponent_bit_position())) - 1
+    }
+
+    /// Max fraction value, with all fraction bits set.
+    fn fraction_max() -> u64 {
+        (1 << Self::exponent_bit_position()) - 1
+    }
+
+    /// Highest fraction bit, telling a quiet NaN from a signalling NaN.
+    fn quiet_nan_bit() -> u64 {
+        1 << (Self::exponent_bit_position() - 1)
+    }
+
     /// Transform float value to bits.
     fn to_bits(&self) -> u64;
 
@@ -68,11 +79,6 @@ impl FloatParts for f32 {
         0xfe
     }
 
-    #[cfg(test)]
-    fn fraction_normal_max() -> u64 {
-        0x7fffff
-    }
-
     fn exponent_bit_position() -> u8 {
         23
     }
@@ -96,11 +102,6 @@ impl FloatParts for f64 {
         0x7fe
     }
 
-    #[cfg(test)]
-    fn fraction_normal_max() -> u64 {
-        0xfffffffffffff
-    }
-
     fn exponent_bit_position() -> u8 {
         52
     }
@@ -132,11 +133,11 @@ mod test {
     }
 
     fn f32_frac() -> BoxGen<u64> {
-        gens::u64::ranged(0..=f32::fraction_normal_max())
+        gens::u64::ranged(0..=f32::fraction_max())
     }
 
     fn f64_frac() -> BoxGen<u64> {
-        gens::u64::ranged(0..=f64::fraction_normal_max())
+        gens::u64::ranged(0..=f64::fraction_max())
     }
 
     fn compose_f32(
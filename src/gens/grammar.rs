@@ -0,0 +1,584 @@
+//! Generators of structured text from context-free grammars.
+//!
+//! A grammar is a set of rules, each rule giving alternative expansions of a
+//! nonterminal into sequences of terminals and nonterminals. The first rule
+//! added is the start rule. A grammar is built either with the builder API:
+//!
+//! ```rust
+//! use monkey_test::*;
+//! use monkey_test::gens::grammar::Grammar;
+//! use monkey_test::gens::grammar::nonterminal as n;
+//! use monkey_test::gens::grammar::terminal as t;
+//!
+//! let sums = Grammar::new()
+//!     .with_rule("sum", &[&[n("num")], &[n("num"), t("+"), n("sum")]])
+//!     .with_rule("num", &[&[t("1")], &[t("2")], &[t("3")]])
+//!     .strings();
+//!
+//! monkey_test()
+//!     .with_generator(sums)
+//!     .assert_true(|sum| !sum.starts_with('+') && !sum.ends_with('+'));
+//! ```
+//!
+//! or from text in BNF, with the EBNF extensions `[optional]`,
+//! `{repeated}`, `(grouped)` and the postfix operators `?`, `*` and `+`:
+//!
+//! ```rust
+//! use monkey_test::*;
+//! use monkey_test::gens::grammar::Grammar;
+//!
+//! let sums = Grammar::from_bnf(r#"
+//!     <sum> ::= <num> { "+" <num> }
+//!     <num> ::= "1" | "2" | "3"
+//! "#)
+//! .strings();
+//! ```
+//!
+//! Nonterminals are expanded by randomly chosen alternatives, at most as many
+//! times as the upper bound of the example size, and nested at most as deep as
+//! the upper bound of the example size, but at most 32 levels deep. Remaining
+//! nonterminals are expanded by their shortest expansion.
+//!
+//! Examples are shrunk by replacing nonterminal expansions with the shortest
+//! expansion of the same nonterminal, with a nested expansion of the same
+//! nonterminal or with an earlier alternative. Examples not generated by the
+//! grammar generator itself are not shrunk.
+
+use crate::internal::example_memory::ExampleMemory;
+use crate::BoxGen;
+use crate::BoxIter;
+use crate::BoxShrink;
+use rand::Rng;
+use rand::SeedableRng;
+use std::rc::Rc;
+
+/// Maximum depth of nested nonterminals expanded by random alternatives.
+const MAX_DEPTH: usize = 32;
+
+/// Symbol in the alternative expansion of a nonterminal.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Symbol {
+    /// Text to include as is in generated examples.
+    Terminal(String),
+    /// Nonterminal to expand further, by its name.
+    Nonterminal(String),
+}
+
+/// Shorthand for creating a [Symbol::Terminal].
+pub fn terminal(text: &str) -> Symbol {
+    Symbol::Terminal(text.to_string())
+}
+
+/// Shorthand for creating a [Symbol::Nonterminal].
+pub fn nonterminal(name: &str) -> Symbol {
+    Symbol::Nonterminal(name.to_string())
+}
+
+/// Context-free grammar, see [module documentation](self).
+#[derive(Clone, Debug, Default)]
+pub struct Grammar {
+    rules: Vec<(String, Vec<Vec<Symbol>>)>,
+}
+
+impl Grammar {
+    /// Create grammar without rules.
+    pub fn new() -> Grammar {
+        Grammar::default()
+    }
+
+    /// Create grammar from text in BNF, see
+    /// [module documentation](self). Each rule has the form
+    /// `<name> ::= alternative | alternative ...`, where terminals are quoted
+    /// with `"` or `'`. Lines starting with `#` are comments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the text is not valid BNF.
+    pub fn from_bnf(text: &str) -> Grammar {
+        let rules =
+            crate::internal::bnf::parse(text).unwrap_or_else(|reason| {
+                panic!("Invalid grammar: {reason}");
+            });
+
+        rules
+            .into_iter()
+            .fold(Grammar::new(), |grammar, (name, alts)| {
+                grammar.with_rule_alternatives(&name, alts)
+            })
+    }
+
+    /// Add rule with given alternative expansions of nonterminal with given
+    /// name. Adding a rule for a nonterminal that already has a rule, adds
+    /// the alternatives to that rule.
+    pub fn with_rule(self, name: &str, alternatives: &[&[Symbol]]) -> Grammar {
+        self.with_rule_alternatives(
+            name,
+            alternatives.iter().map(|alt| alt.to_vec()).collect(),
+        )
+    }
+
+    fn with_rule_alternatives(
+        self,
+        name: &str,
+        alternatives: Vec<Vec<Symbol>>,
+    ) -> Grammar {
+        let mut rules = self.rules;
+        match rules.iter_mut().find(|(rule_name, _)| rule_name == name) {
+            Some((_, existing)) => existing.extend(alternatives),
+            None => rules.push((name.to_string(), alternatives)),
+        }
+        Grammar { rules }
+    }
+
+    /// Generator of strings, concatenating the terminals of each expansion.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grammar has no rules, refers to nonterminals without
+    /// rules or has nonterminals that can never be fully expanded.
+    pub fn strings(&self) -> BoxGen<String> {
+        from_expansions(Rc::new(self.compile()), |expansion, grammar| {
+            expansion.terminals(grammar).concat()
+        })
+    }
+
+    /// Generator of token sequences, with the terminals of each expansion as
+    /// tokens. Empty terminals are left out.
+    ///
+    /// # Panics
+    ///
+    /// Panics like [Grammar::strings].
+    pub fn tokens(&self) -> BoxGen<Vec<String>> {
+        from_expansions(Rc::new(self.compile()), |expansion, grammar| {
+            expansion
+                .terminals(grammar)
+                .into_iter()
+                .filter(|token| !token.is_empty())
+                .map(|token| token.to_string())
+                .collect()
+        })
+    }
+
+    fn compile(&self) -> Compiled {
+        if self.rules.is_empty() {
+            panic!("Grammar has no rules.");
+        }
+
+        let index_of = |name: &str| {
+            self.rules
+                .iter()
+                .position(|(rule_name, _)| rule_name == name)
+                .unwrap_or_else(|| {
+                    panic!("Grammar has no rule for nonterminal <{name}>.")
+                })
+        };
+
+        let rules = self
+            .rules
+            .iter()
+            .map(|(_, alternatives)| {
+                alternatives
+                    .iter()
+                    .map(|alternative| {
+                        alternative
+                            .iter()
+                            .map(|symbol| match symbol {
+                                Symbol::Terminal(text) => {
+                                    Compiled::terminal(text)
+                                }
+                                Symbol::Nonterminal(name) => {
+                                    Part::Nonterminal(index_of(name))
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let names = self.rules.iter().map(|(name, _)| name.clone()).collect();
+        Compiled::new(names, rules)
+    }
+}
+
+/// Part of an alternative, with nonterminals referred to by index.
+#[derive(Clone, Debug)]
+enum Part {
+    Terminal(Rc<str>),
+    Nonterminal(usize),
+}
+
+/// Grammar with resolved nonterminals and shortest expansions.
+#[derive(Debug)]
+struct Compiled {
+    rules: Vec<Vec<Vec<Part>>>,
+    /// Alternative of each nonterminal with the shortest expansion.
+    shortest: Vec<usize>,
+}
+
+impl Compiled {
+    fn terminal(text: &str) -> Part {
+        Part::Terminal(Rc::from(text))
+    }
+
+    fn new(names: Vec<String>, rules: Vec<Vec<Vec<Part>>>) -> Compiled {
+        // Length of shortest expansion per nonterminal, improved until no
+        // more improvements are found. Only strict improvements are made, so
+        // that following shortest alternatives always ends.
+        let mut lengths: Vec<Option<usize>> = vec![None; rules.len()];
+        let mut shortest = vec![0; rules.len()];
+        let mut improved = true;
+
+        while improved {
+            improved = false;
+            for (nonterminal, alternatives) in rules.iter().enumerate() {
+                for (index, alternative) in alternatives.iter().enumerate() {
+                    let length = alternative
+                        .iter()
+                        .map(|part| match part {
+                            Part::Terminal(text) => Some(text.chars().count()),
+                            Part::Nonterminal(other) => lengths[*other],
+                        })
+                        .sum::<Option<usize>>();
+
+                    if let Some(length) = length {
+                        if lengths[nonterminal].map_or(true, |l| length < l) {
+                            lengths[nonterminal] = Some(length);
+                            shortest[nonterminal] = index;
+                            improved = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(nonterminal) = lengths.iter().position(Option::is_none) {
+            panic!(
+                "Nonterminal <{}> can never be fully expanded.",
+                names[nonterminal]
+            );
+        }
+
+        Compiled { rules, shortest }
+    }
+
+    /// Expand nonterminal by random alternatives, at most `budget` times in
+    /// total and down to given max depth, and by shortest expansions beyond
+    /// that.
+    fn expand<R: Rng>(
+        &self,
+        nonterminal: usize,
+        depth: usize,
+        max_depth: usize,
+        budget: &mut usize,
+        rng: &mut R,
+    ) -> Expansion {
+        if depth >= max_depth || *budget == 0 {
+            return self.shortest_expansion(nonterminal);
+        }
+        *budget -= 1;
+
+        let alternative =
+            rng.random_range(0..self.rules[nonterminal].len().max(1));
+        self.expand_alternative(nonterminal, alternative, |nonterminal| {
+            self.expand(nonterminal, depth + 1, max_depth, budget, rng)
+        })
+    }
+
+    fn shortest_expansion(&self, nonterminal: usize) -> Expansion {
+        self.expand_alternative(
+            nonterminal,
+            self.shortest[nonterminal],
+            |nonterminal| self.shortest_expansion(nonterminal),
+        )
+    }
+
+    fn expand_alternative(
+        &self,
+        nonterminal: usize,
+        alternative: usize,
+        mut expand: impl FnMut(usize) -> Expansion,
+    ) -> Expansion {
+        let children = self.rules[nonterminal]
+            .get(alternative)
+            .map(|parts| {
+                parts
+                    .iter()
+                    .map(|part| match part {
+                        Part::Terminal(_) => None,
+                        Part::Nonterminal(nonterminal) => {
+                            Some(expand(*nonterminal))
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Expansion {
+            nonterminal,
+            alternative,
+            children,
+        }
+    }
+}
+
+/// Expansion of a nonterminal by one of its alternatives, with the
+/// expansions of the nonterminals in that alternative as children.
+#[derive(Clone, Debug, PartialEq)]
+struct Expansion {
+    nonterminal: usize,
+    alternative: usize,
+    /// One child per part of the alternative, `None` for terminals.
+    children: Vec<Option<Expansion>>,
+}
+
+impl Expansion {
+    fn terminals<'a>(&'a self, grammar: &'a Compiled) -> Vec<&'a str> {
+        let mut result = Vec::new();
+        self.collect_terminals(grammar, &mut result);
+        result
+    }
+
+    fn collect_terminals<'a>(
+        &'a self,
+        grammar: &'a Compiled,
+        result: &mut Vec<&'a str>,
+    ) {
+        let parts = self.parts(grammar);
+        for (part, child) in parts.iter().zip(&self.children) {
+            match (part, child) {
+                (Part::Terminal(text), _) => result.push(text),
+                (_, Some(child)) => child.collect_terminals(grammar, result),
+                (_, None) => {}
+            }
+        }
+    }
+
+    fn parts<'a>(&self, grammar: &'a Compiled) -> &'a [Part] {
+        grammar.rules[self.nonterminal]
+            .get(self.alternative)
+            .map(|parts| parts.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Paths to all nested expansions, in pre-order.
+    fn paths(&self) -> Vec<Vec<usize>> {
+        let mut result = vec![vec![]];
+        for (index, child) in self.children.iter().enumerate() {
+            if let Some(child) = child {
+                result.extend(child.paths().into_iter().map(|mut path| {
+                    path.insert(0, index);
+                    path
+                }));
+            }
+        }
+        result
+    }
+
+    fn at(&self, path: &[usize]) -> &Expansion {
+        match path.split_first() {
+            None => self,
+            Some((index, rest)) => self.children[*index]
+                .as_ref()
+                .expect("path to expansion")
+                .at(rest),
+        }
+    }
+
+    fn replaced(&self, path: &[usize], replacement: Expansion) -> Expansion {
+        match path.split_first() {
+            None => replacement,
+            Some((index, rest)) => {
+                let mut result = self.clone();
+                result.children[*index] =
+                    Some(self.at(&[*index]).replaced(rest, replacement));
+                result
+            }
+        }
+    }
+
+    /// Smaller expansions to replace this expansion with: the shortest
+    /// expansion, nested expansions of the same nonterminal and earlier
+    /// alternatives.
+    fn replacements(&self, grammar: &Compiled) -> Vec<Expansion> {
+        let shortest = grammar.shortest_expansion(self.nonterminal);
+
+        let nested = self
+            .paths()
+            .into_iter()
+            .skip(1)
+            .map(|path| self.at(&path).clone())
+            .filter(|nested| nested.nonterminal == self.nonterminal);
+
+        let earlier = (0..self.alternative).map(|alternative| {
+            grammar.expand_alternative(
+                self.nonterminal,
+                alternative,
+                |nonterminal| grammar.shortest_expansion(nonterminal),
+            )
+        });
+
+        std::iter::once(shortest)
+            .chain(nested)
+            .chain(earlier)
+            .filter(|replacement| replacement != self)
+            .collect()
+    }
+}
+
+fn from_expansions<E>(
+    grammar: Rc<Compiled>,
+    render: fn(&Expansion, &Compiled) -> E,
+) -> BoxGen<E>
+where
+    E: Clone + core::fmt::Debug + std::hash::Hash + Eq + 'static,
+{
+    let memory = ExampleMemory::<E, Rc<Expansion>>::default();
+    let shrinker = shrinker(grammar.clone(), memory.clone(), render);
+
+    crate::gens::from_fn(move |seed, size| {
+        let grammar = grammar.clone();
+        let memory = memory.clone();
+        let max_depth = (*size.end()).min(MAX_DEPTH);
+        let max_expansions = *size.end();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+
+        std::iter::from_fn(move || {
+            let mut budget = max_expansions;
+            let expansion =
+                grammar.expand(0, 0, max_depth, &mut budget, &mut rng);
+            let example = render(&expansion, &grammar);
+            memory.remember(&example, Rc::new(expansion));
+            Some(example)
+        })
+    })
+    .with_shrinker(shrinker)
+}
+
+fn shrinker<E>(
+    grammar: Rc<Compiled>,
+    memory: ExampleMemory<E, Rc<Expansion>>,
+    render: fn(&Expansion, &Compiled) -> E,
+) -> BoxShrink<E>
+where
+    E: Clone + core::fmt::Debug + std::hash::Hash + Eq + 'static,
+{
+    crate::shrinks::from_fn(move |original: E| {
+        let grammar = grammar.clone();
+        let memory = memory.clone();
+
+        let candidates: BoxIter<E> = match memory.recall(&original) {
+            None => Box::new(std::iter::empty()),
+            Some(expansion) => {
+                Box::new(expansion.paths().into_iter().flat_map(move |path| {
+                    let replacements =
+                        expansion.at(&path).replacements(&grammar);
+                    let grammar = grammar.clone();
+                    let expansion = expansion.clone();
+                    let memory = memory.clone();
+
+                    replacements.into_iter().map(move |replacement| {
+                        let candidate = expansion.replaced(&path, replacement);
+                        let example = render(&candidate, &grammar);
+                        memory.remember(&example, Rc::new(candidate));
+                        example
+                    })
+                }))
+            }
+        };
+        candidates
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::nonterminal as n;
+    use super::terminal as t;
+    use super::Grammar;
+    use crate::testing::assert_iter_eq;
+
+    fn sums() -> Grammar {
+        Grammar::new()
+            .with_rule("sum", &[&[n("num")], &[n("num"), t("+"), n("sum")]])
+            .with_rule("num", &[&[t("1")], &[t("2")]])
+            .with_rule("num", &[&[t("("), n("sum"), t(")")]])
+    }
+
+    #[test]
+    fn expands_to_shortest_expansion_at_zero_depth() {
+        assert_iter_eq(
+            sums().strings().examples(1337, 0..=0).take(3),
+            vec!["1".to_string(), "1".to_string(), "1".to_string()],
+            "should only use shortest expansion",
+        );
+    }
+
+    #[test]
+    fn number_of_expansions_is_bounded_by_example_size() {
+        let generator = Grammar::new()
+            .with_rule("e", &[&[n("e"), n("e"), n("e")], &[t("x")]])
+            .strings();
+
+        // Each of at most 100 random expansions adds at most two more
+        // nonterminals, each expanded to a single character.
+        assert!(generator
+            .examples(1337, 0..=100)
+            .take(100)
+            .all(|example| example.len() <= 201));
+    }
+
+    #[test]
+    fn shrinks_by_replacing_expansions() {
+        let generator = sums().strings();
+        let example = generator
+            .examples(1337, 0..=5)
+            .find(|example| example.len() > 10)
+            .unwrap();
+
+        let candidates = generator
+            .shrinker()
+            .candidates(example.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(candidates.first(), Some(&"1".to_string()));
+        assert!(
+            candidates
+                .iter()
+                .all(|candidate| candidate.len() < example.len()
+                    || candidate.len() == example.len()
+                        && *candidate < example),
+            "candidates of {example:?} should be smaller, but got \
+            {candidates:?}"
+        );
+    }
+
+    #[test]
+    fn tokens_leave_out_empty_terminals() {
+        let generator = Grammar::new()
+            .with_rule("list", &[&[t("x"), t(""), t("y")]])
+            .tokens();
+
+        assert_iter_eq(
+            generator.examples(1337, 0..=10).take(1),
+            vec![vec!["x".to_string(), "y".to_string()]],
+            "should not contain empty token",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Grammar has no rule for nonterminal <missing>.")]
+    fn panics_on_missing_rule() {
+        Grammar::new().with_rule("a", &[&[n("missing")]]).strings();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Nonterminal <loop> can never be fully expanded."
+    )]
+    fn panics_on_never_ending_expansion() {
+        Grammar::new()
+            .with_rule("a", &[&[t("x")], &[n("loop")]])
+            .with_rule("loop", &[&[t("y"), n("loop")]])
+            .strings();
+    }
+}
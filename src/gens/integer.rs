@@ -1,5 +1,6 @@
 //! Generic generators for integer type values.
 
+use crate::internal::sample_target::Ratio;
 use crate::BoxGen;
 use num_traits::PrimInt;
 use rand::distr::uniform::SampleUniform;
@@ -24,10 +25,14 @@ where
         extreme_values.push(E::zero());
     }
 
-    let extremes = crate::gens::pick_evenly(&extreme_values);
+    let extremes = crate::gens::pick_evenly(&extreme_values)
+        .with_shrinker(crate::shrinks::int_in_range(min, max));
     let randoms = completely_random(bounds);
 
-    crate::gens::mix_with_ratio(&[(96, randoms), (6, extremes)])
+    crate::gens::mix::mix_with_ratio_first_shrinking(&[
+        (96, randoms),
+        (6, extremes),
+    ])
 }
 
 /// Int generator with completely random distribution. This function has a long
@@ -47,6 +52,61 @@ where
     .with_shrinker(crate::shrinks::int_in_range(min, max))
 }
 
+/// Range of values mixing completely random values with bit pattern edge
+/// cases at given ratios. Edge cases are the bounds, zero, powers of two and
+/// their negations, each ±1, and all-ones patterns in the low and high bits.
+/// This covers the boundaries of all narrower integer types, like
+/// `i32::MAX as i64 + 1` when generating `i64`. Only edge cases in given
+/// bounds are used.
+pub fn edge_biased<E, B>(
+    bounds: B,
+    ratio_random: Ratio,
+    ratio_edge_cases: Ratio,
+) -> BoxGen<E>
+where
+    E: PrimInt + SampleUniform + std::fmt::Debug + 'static,
+    B: RangeBounds<E>,
+{
+    let (min, max) = int_bounds::to_inclusive_range_tuple(&bounds);
+    let edge_cases = crate::gens::pick_evenly(&edge_cases(min, max))
+        .with_shrinker(crate::shrinks::int_in_range(min, max));
+    let randoms = completely_random(bounds);
+
+    crate::gens::mix::mix_with_ratio_first_shrinking(&[
+        (ratio_random, randoms),
+        (ratio_edge_cases, edge_cases),
+    ])
+}
+
+/// Bit pattern edge cases in range of min and max, in ascending order.
+fn edge_cases<E: PrimInt>(min: E, max: E) -> Vec<E> {
+    let bits = E::zero().count_zeros() as usize;
+    let mut candidates = vec![min, max, E::zero(), !E::zero()];
+
+    for shift in 0..bits {
+        let power = E::one() << shift;
+        let high_ones = !E::zero() << shift;
+        let neighbours = [
+            Some(power),
+            power.checked_sub(&E::one()),
+            power.checked_add(&E::one()),
+        ];
+
+        for value in neighbours.into_iter().flatten() {
+            candidates.push(value);
+            if let Some(negated) = E::zero().checked_sub(&value) {
+                candidates.push(negated);
+            }
+        }
+        candidates.push(high_ones);
+    }
+
+    candidates.retain(|value| min <= *value && *value <= max);
+    candidates.sort();
+    candidates.dedup();
+    candidates
+}
+
 #[cfg(test)]
 mod tests {
     use crate::testing::assert_generator_can_shrink;
@@ -82,6 +142,41 @@ mod tests {
         assert_generator_has_distribution_within_percent(ranged, expected, 1.0)
     }
 
+    #[test]
+    fn edge_cases_are_bit_patterns_in_range() {
+        assert_eq!(
+            super::edge_cases(i8::MIN, i8::MAX),
+            vec![
+                -128, -127, -65, -64, -63, -33, -32, -31, -17, -16, -15, -9,
+                -8, -7, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5, 7, 8, 9, 15, 16,
+                17, 31, 32, 33, 63, 64, 65, 127
+            ]
+        );
+        assert_eq!(
+            super::edge_cases(100u8, 200u8),
+            vec![100, 127, 128, 129, 192, 200]
+        );
+    }
+
+    #[test]
+    fn edge_biased_with_only_edge_cases_stays_in_bounds() {
+        let edge_cases = super::edge_cases(-1000i64, 1000);
+
+        for value in super::edge_biased(-1000i64..=1000, 0, 1)
+            .examples(1337, 0..=100)
+            .take(1000)
+        {
+            assert!(edge_cases.contains(&value), "{value}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Given arguments ratio_random and \
+        ratio_edge_cases can not both be zero.")]
+    fn edge_biased_panics_on_zero_ratios() {
+        crate::gens::i64::edge_biased(.., 0, 0);
+    }
+
     #[test]
     fn has_shrinker() {
         assert_generator_can_shrink(super::ranged(..1000), 123);
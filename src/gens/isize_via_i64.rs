@@ -1,27 +1,20 @@
-//! Generators for values of type `isize`.
+//! Generic integer generators for type `isize`, used by the public module
+//! [crate::gens::isize].
 
-// Please note! This module does not use the integer_module macro, since the
-// underlying rand library does not support isize directly. For details, see:
-// https://rust-random.github.io/book/update-0.9.html
+// Please note! The underlying rand library does not support isize directly.
+// For details, see: https://rust-random.github.io/book/update-0.9.html
 //
-// The code here should be similar to what the macro would have generated, but
-// is piggybacking on the generators for type `i64` and just maps to type
-// `isize`.
+// The code here is therefore piggybacking on the generators for type `i64`
+// and just maps to type `isize`.
 
 use crate::internal::int_bounds;
+use crate::internal::sample_target::Ratio;
 use crate::BoxGen;
 use crate::*;
 use core::panic;
 use std::ops::{RangeBounds, RangeInclusive};
 
-/// Roughly uniformly distributed unbound range of values, with
-/// some overwheight to extremes (min and max).
-pub fn any() -> BoxGen<isize> {
-    ranged(..)
-}
-
-/// Roughly uniformly distributed range of values, with some
-/// overwheight to extremes (min and max) of given bounds.
+/// See [crate::gens::integer::ranged].
 pub fn ranged<B>(bounds: B) -> BoxGen<isize>
 where
     B: RangeBounds<isize>,
@@ -31,8 +24,7 @@ where
     map_to_isize_gen(super::i64::ranged(i64_bounds))
 }
 
-/// Int generator with completely random distribution. This
-/// function has a long name, since `ranged` should be preferred.
+/// See [crate::gens::integer::completely_random].
 pub fn completely_random<B>(bounds: B) -> BoxGen<isize>
 where
     B: RangeBounds<isize>,
@@ -42,6 +34,24 @@ where
     map_to_isize_gen(super::i64::completely_random(i64_bounds))
 }
 
+/// See [crate::gens::integer::edge_biased].
+pub fn edge_biased<B>(
+    bounds: B,
+    ratio_random: Ratio,
+    ratio_edge_cases: Ratio,
+) -> BoxGen<isize>
+where
+    B: RangeBounds<isize>,
+{
+    assert_lib_supports_isize_bit_width();
+    let i64_bounds = map_to_i64_bounds(&bounds);
+    map_to_isize_gen(super::i64::edge_biased(
+        i64_bounds,
+        ratio_random,
+        ratio_edge_cases,
+    ))
+}
+
 /// Maps isize bounds to i64 bounds.
 fn map_to_i64_bounds<B>(i_bounds: &B) -> RangeInclusive<i64>
 where
@@ -1,12 +1,21 @@
 use crate::internal::sample_target::Ratio;
 use crate::internal::sample_target::SampleTarget;
 use crate::BoxGen;
+use crate::BoxIter;
 use crate::BoxShrink;
-use rand::Rng;
 use rand::SeedableRng;
 
 /// Mix values from given generators evenly.
 ///
+/// Each example is shrunk with the shrinkers of all given generators,
+/// interleaving their candidates fairly. Shrinkers of generators not producing
+/// the example mostly have no candidates for it, so the example is shrunk with
+/// the shrinker of the generator producing it. When the generators produce
+/// overlapping values, the example can also shrink toward simpler values of
+/// the other generators. For example, a number below 20 from
+/// `gens::u8::any()` mixed with `gens::u8::ranged(1..20)` still shrinks toward
+/// zero.
+///
 /// Example, where a mixed and non-uniform distribution of `u8` values ensuring
 /// extremes (`u8` min and max) are tested with 33% chance:
 /// ```rust
@@ -22,17 +31,14 @@ pub fn mix_evenly<E>(generators: &[BoxGen<E>]) -> BoxGen<E>
 where
     E: Clone + 'static + core::fmt::Debug,
 {
-    mix_with_sample_target(
-        generators
-            .first()
-            .map(|g| g.shrinker())
-            .unwrap_or(crate::shrinks::none()),
-        SampleTarget::evenly(generators),
-    )
+    mix_with_sample_target(SampleTarget::evenly(generators))
 }
 
 /// Mix values from given generators in given ratios.
 ///
+/// Each example is shrunk with the shrinkers of all given generators, see
+/// [mix_evenly].
+///
 /// Example, where a mixed and non-uniform distribution of `u8` values ensuring
 /// extremes (`u8` min and max) are tested with a 10% *=1/(4+5+1)* chance:
 /// ```rust
@@ -48,44 +54,114 @@ pub fn mix_with_ratio<E>(ratios_and_gens: &[(Ratio, BoxGen<E>)]) -> BoxGen<E>
 where
     E: Clone + 'static + core::fmt::Debug,
 {
-    mix_with_sample_target(
-        ratios_and_gens
-            .first()
-            .map(|pair| pair.1.shrinker())
-            .unwrap_or(crate::shrinks::none()),
-        SampleTarget::with_ratios(ratios_and_gens),
-    )
+    mix_with_sample_target(SampleTarget::with_ratios(ratios_and_gens))
+}
+
+/// Mix values from given generators, with frequencies by given floating
+/// point weights. Unlike [mix_with_ratio], any iterable collection of
+/// weights and generators is accepted and weights can be arbitrarily skewed.
+///
+/// Each example is shrunk with the shrinkers of all given generators, see
+/// [mix_evenly].
+///
+/// Example, where a rare generator is used once every 10 000 examples:
+/// ```rust
+/// use monkey_test::*;
+///
+/// let common = gens::u16::ranged(1..20);
+/// let rare = gens::u16::any();
+///
+/// let mixed = gens::frequency(vec![(9999.0, common), (1.0, rare)]);
+/// ```
+pub fn frequency<E>(
+    weights_and_gens: impl IntoIterator<Item = (f64, BoxGen<E>)>,
+) -> BoxGen<E>
+where
+    E: Clone + 'static + core::fmt::Debug,
+{
+    mix_with_sample_target(SampleTarget::with_weights(weights_and_gens))
 }
 
 fn mix_with_sample_target<E>(
-    shrinker: BoxShrink<E>,
     sample_target: SampleTarget<BoxGen<E>>,
 ) -> BoxGen<E>
 where
     E: Clone + 'static + core::fmt::Debug,
 {
+    let shrinkers = sample_target
+        .targets()
+        .map(|generator| generator.shrinker())
+        .collect::<Vec<_>>();
+
     crate::gens::from_fn(move |seed, size| {
-        let high = sample_target.sample_domain_max();
-        let distr = rand::distr::Uniform::new_inclusive(1usize, high).unwrap();
-        let rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
 
         let mut sample_iterators = sample_target
             .clone()
             .map(|g| g.examples(seed, size.clone()));
 
-        rng.sample_iter(distr)
-            .map(move |sample| {
-                sample_iterators
-                    .target_from_sample_mut(sample)
-                    .map(|it| it.next())
-                    .unwrap()
+        // Some of the internal generators being empty (returning None) is
+        // the end criteria for the whole mixing generator.
+        std::iter::from_fn(move || sample_iterators.pick_mut(&mut rng).next())
+    })
+    .with_shrinker(all_shrinking(shrinkers))
+}
+
+/// Mix values from given generators in given ratios, like [mix_with_ratio],
+/// but shrinking each example only with the first shrinker having any
+/// candidate for it.
+///
+/// Only for built in generators mixing generators that share one shrinker or
+/// whose shrinkers only shrink their own examples, where interleaving the
+/// candidates of all shrinkers would repeat candidates or leave the range of
+/// the mixed generator.
+pub(crate) fn mix_with_ratio_first_shrinking<E>(
+    ratios_and_gens: &[(Ratio, BoxGen<E>)],
+) -> BoxGen<E>
+where
+    E: Clone + 'static + core::fmt::Debug,
+{
+    let shrinkers = ratios_and_gens
+        .iter()
+        .map(|(_, generator)| generator.shrinker())
+        .collect::<Vec<_>>();
+
+    mix_with_ratio(ratios_and_gens).with_shrinker(first_shrinking(shrinkers))
+}
+
+/// Shrinker interleaving the candidates of all given shrinkers fairly,
+/// starting with the first shrinker.
+fn all_shrinking<E>(shrinkers: Vec<BoxShrink<E>>) -> BoxShrink<E>
+where
+    E: Clone + 'static,
+{
+    crate::shrinks::from_fn_boxed(move |original: E| {
+        crate::shrinks::interleave(
+            shrinkers
+                .iter()
+                .map(|shrinker| shrinker.candidates(original.clone()))
+                .collect(),
+        )
+    })
+}
+
+/// Shrinker using the candidates of the first of given shrinkers having any
+/// candidate for the original example.
+fn first_shrinking<E>(shrinkers: Vec<BoxShrink<E>>) -> BoxShrink<E>
+where
+    E: Clone + 'static,
+{
+    crate::shrinks::from_fn_boxed(move |original: E| {
+        shrinkers
+            .iter()
+            .find_map(|shrinker| {
+                let mut candidates = shrinker.candidates(original.clone());
+                let first = candidates.next()?;
+                Some(Box::new(std::iter::once(first).chain(candidates))
+                    as BoxIter<E>)
             })
-            // Some of the internal generators being empty (returning None) is
-            // the end criteria for the whole mixing generator.
-            .take_while(|opt| opt.is_some())
-            .flatten()
+            .unwrap_or_else(|| Box::new(std::iter::empty()))
     })
-    .with_shrinker(shrinker)
 }
 
 #[cfg(test)]
@@ -160,6 +236,18 @@ mod test {
         assert_generator_has_distribution_within_percent(mixer, expected, 1.0)
     }
 
+    #[test]
+    fn frequency_mixes_according_to_weights() {
+        let mixer = super::frequency(vec![
+            (0.75, crate::gens::fixed::constant('A')),
+            (0.25, crate::gens::fixed::constant('B')),
+        ]);
+
+        let expected = distribution_from_pairs(&[(3, 'A'), (1, 'B')]);
+
+        assert_generator_has_distribution_within_percent(mixer, expected, 1.0)
+    }
+
     /// A and B will be 2 times more frequent than 1, 2, 3, and 4, just because
     /// there are half the number ov values to choose from in first generator.
     #[test]
@@ -182,18 +270,134 @@ mod test {
     }
 
     #[test]
-    fn mix_should_reuse_shrinker_from_first_generator() {
-        let with_shrinker = super::mix_evenly(&[
+    fn mix_should_use_any_shrinker_having_candidates() {
+        let first_can_shrink = super::mix_evenly(&[
             crate::gens::u8::any().with_shrinker(crate::shrinks::int_to_zero()),
             crate::gens::u8::any().with_shrinker(crate::shrinks::none()),
         ]);
 
-        let without_shrinker = super::mix_evenly(&[
+        let second_can_shrink = super::mix_evenly(&[
             crate::gens::u8::any().with_shrinker(crate::shrinks::none()),
             crate::gens::u8::any().with_shrinker(crate::shrinks::int_to_zero()),
         ]);
 
-        assert_generator_can_shrink(with_shrinker, 10);
-        assert_generator_cannot_shrink(without_shrinker, 10);
+        let none_can_shrink = super::mix_evenly(&[
+            crate::gens::u8::any().with_shrinker(crate::shrinks::none()),
+            crate::gens::u8::any().with_shrinker(crate::shrinks::none()),
+        ]);
+
+        assert_generator_can_shrink(first_can_shrink, 10);
+        assert_generator_can_shrink(second_can_shrink, 10);
+        assert_generator_cannot_shrink(none_can_shrink, 10);
+    }
+
+    #[test]
+    fn mix_should_shrink_example_with_shrinker_of_producing_generator() {
+        let mixer = super::mix_evenly(&[
+            crate::gens::u8::ranged(0..=9),
+            crate::gens::u8::ranged(100..=199),
+        ]);
+
+        let examples = mixer.examples(1337, 0..=1000).take(100);
+
+        for example in examples {
+            let range = if example < 100 { 0..=9 } else { 100..=199 };
+            for candidate in mixer.shrinker().candidates(example).take(100) {
+                assert!(
+                    range.contains(&candidate),
+                    "candidate {candidate} of {example} should stay in range \
+                    {range:?} of producing generator"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn mix_should_keep_shrinking_candidates_with_same_shrinker() {
+        let mixer = super::mix_evenly(&[
+            crate::gens::u8::ranged(0..=9),
+            crate::gens::u8::ranged(100..=199),
+        ]);
+
+        let example = mixer
+            .examples(1337, 0..=1000)
+            .find(|example| *example > 150)
+            .unwrap();
+        let candidate = mixer
+            .shrinker()
+            .candidates(example)
+            .find(|candidate| *candidate > 100)
+            .unwrap();
+
+        assert!(
+            mixer
+                .shrinker()
+                .candidates(candidate)
+                .all(|c| (100..=199).contains(&c)),
+            "candidate should be shrunk by the shrinker of its branch",
+        );
+    }
+
+    #[test]
+    fn mix_should_interleave_candidates_of_all_shrinkers() {
+        let mixer = super::mix_evenly(&[
+            crate::gens::u8::ranged(1..20),
+            crate::gens::u8::any(),
+        ]);
+
+        assert_iter_eq(
+            mixer.shrinker().candidates(5).take(6),
+            vec![1, 0, 3, 3, 4, 4],
+            "candidates of both overlapping branches should be interleaved, \
+            starting with the first branch",
+        );
+    }
+
+    #[test]
+    fn mix_should_shrink_toward_zero_also_when_branches_overlap() {
+        let mixer = super::mix_evenly(&[
+            crate::gens::u8::ranged(1..20),
+            crate::gens::u8::any(),
+        ]);
+        let any_example = mixer
+            .examples(1337, 0..=1000)
+            .find(|example| (20..40).contains(example))
+            .unwrap();
+        let overlapping = mixer
+            .shrinker()
+            .candidates(any_example)
+            .find(|candidate| (1..20).contains(candidate))
+            .unwrap();
+
+        assert!(
+            mixer.shrinker().candidates(overlapping).any(|c| c == 0),
+            "candidate in range of first branch should still shrink to zero \
+            by shrinker of second branch",
+        );
+    }
+
+    #[test]
+    fn first_shrinking_mix_should_only_use_first_shrinker_having_candidates() {
+        let mixer = super::mix_with_ratio_first_shrinking(&[
+            (1, crate::gens::u8::ranged(1..20)),
+            (1, crate::gens::u8::any()),
+        ]);
+
+        assert_iter_eq(
+            mixer.shrinker().candidates(5),
+            crate::shrinks::int_in_range(1u8, 19)
+                .candidates(5)
+                .collect::<Vec<_>>(),
+            "example in range of first branch should only be shrunk by \
+            shrinker of first branch",
+        );
+        assert_iter_eq(
+            mixer.shrinker().candidates(30),
+            crate::shrinks::int_in_range(u8::MIN, u8::MAX)
+                .candidates(30)
+                .collect::<Vec<_>>(),
+            "example out of range of first branch should be shrunk by \
+            shrinker of second branch",
+        );
     }
 }
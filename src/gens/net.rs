@@ -0,0 +1,103 @@
+//! Generators for network addresses in [std::net].
+
+use crate::BoxGen;
+use crate::BoxShrink;
+use crate::MapWithGen;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::net::SocketAddr;
+
+/// Addresses with a special meaning, in order of shrinking.
+const SPECIAL_IPV4: &[Ipv4Addr] = &[
+    Ipv4Addr::UNSPECIFIED,
+    Ipv4Addr::new(10, 0, 0, 1),
+    Ipv4Addr::LOCALHOST,
+    Ipv4Addr::new(169, 254, 0, 1),
+    Ipv4Addr::new(192, 168, 0, 1),
+    Ipv4Addr::new(224, 0, 0, 1),
+    Ipv4Addr::BROADCAST,
+];
+
+/// Addresses with a special meaning, in order of shrinking.
+const SPECIAL_IPV6: &[Ipv6Addr] = &[
+    Ipv6Addr::UNSPECIFIED,
+    Ipv6Addr::LOCALHOST,
+    Ipv6Addr::new(0, 0, 0, 0, 0, 0xFFFF, 0x7F00, 1),
+    Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 1),
+    Ipv6Addr::new(0xFE80, 0, 0, 0, 0, 0, 0, 1),
+    Ipv6Addr::new(0xFF02, 0, 0, 0, 0, 0, 0, 1),
+    Ipv6Addr::new(0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0, 0, 0, 0),
+];
+
+/// Any IPv4 address, with some overweight to addresses with a special
+/// meaning, like localhost, private and broadcast addresses. Addresses shrink
+/// toward 0.0.0.0.
+pub fn ipv4() -> BoxGen<Ipv4Addr> {
+    let randoms = crate::gens::u32::any().map(Ipv4Addr::from, u32::from);
+    let specials = crate::gens::pick_evenly(SPECIAL_IPV4)
+        .with_shrinker(randoms.shrinker());
+
+    crate::gens::mix::mix_with_ratio_first_shrinking(&[
+        (90, randoms),
+        (10, specials),
+    ])
+}
+
+/// Any IPv6 address, with some overweight to addresses with a special
+/// meaning, like localhost, IPv4-mapped, link-local and multicast addresses.
+/// Addresses shrink toward `::`.
+pub fn ipv6() -> BoxGen<Ipv6Addr> {
+    let randoms = crate::gens::u128::any().map(Ipv6Addr::from, u128::from);
+    let specials = crate::gens::pick_evenly(SPECIAL_IPV6)
+        .with_shrinker(randoms.shrinker());
+
+    crate::gens::mix::mix_with_ratio_first_shrinking(&[
+        (90, randoms),
+        (10, specials),
+    ])
+}
+
+/// Any IPv4 or IPv6 address, evenly distributed between the two, see [ipv4]
+/// and [ipv6]. IPv6 addresses shrink to 0.0.0.0 before shrinking toward `::`.
+pub fn ip() -> BoxGen<IpAddr> {
+    let v4 = crate::gens::from_fn(|seed, size| {
+        ipv4().examples(seed, size).map(IpAddr::V4)
+    });
+    let v6 = crate::gens::from_fn(|seed, size| {
+        ipv6().examples(seed, size).map(IpAddr::V6)
+    });
+
+    crate::gens::mix::mix_with_ratio_first_shrinking(&[(1, v4), (1, v6)])
+        .with_shrinker(ip_shrinker())
+}
+
+fn ip_shrinker() -> BoxShrink<IpAddr> {
+    let v4_shrinker = ipv4().shrinker();
+    let v6_shrinker = ipv6().shrinker();
+
+    crate::shrinks::from_fn_boxed(move |original: IpAddr| match original {
+        IpAddr::V4(ip) => Box::new(v4_shrinker.candidates(ip).map(IpAddr::V4)),
+        IpAddr::V6(ip) => Box::new(
+            std::iter::once(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+                .chain(v6_shrinker.candidates(ip).map(IpAddr::V6)),
+        ),
+    })
+}
+
+/// Any socket address, with an address from [ip] and any port. Socket
+/// addresses shrink toward 0.0.0.0:0.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// monkey_test()
+///     .with_generator(gens::net::socket_addr())
+///     .assert_true(|addr| addr.to_string().parse() == Ok(addr));
+/// ```
+pub fn socket_addr() -> BoxGen<SocketAddr> {
+    crate::gens::zip(ip(), crate::gens::u16::any()).map(
+        |(ip, port)| SocketAddr::new(ip, port),
+        |addr| (addr.ip(), addr.port()),
+    )
+}
@@ -0,0 +1,13 @@
+//! Generators for values of type [std::cmp::Ordering].
+
+use crate::BoxGen;
+use std::cmp::Ordering;
+
+/// Evenly distributed orderings, shrinking toward [Ordering::Equal].
+pub fn any() -> BoxGen<Ordering> {
+    crate::gens::pick_evenly(&[
+        Ordering::Equal,
+        Ordering::Less,
+        Ordering::Greater,
+    ])
+}
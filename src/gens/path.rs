@@ -0,0 +1,129 @@
+//! Generators for values of type [std::path::PathBuf].
+
+use crate::BoxGen;
+use crate::FilterWithGen;
+use crate::MapWithGen;
+use std::path::PathBuf;
+
+/// Path components that often break path handling.
+const EDGE_CASE_COMPONENTS: &[&str] = &[
+    "",
+    ".",
+    "..",
+    " ",
+    "~",
+    "-",
+    ".hidden",
+    "trailing.",
+    "a b",
+    "file.tar.gz",
+    "\u{FC}ber",
+    "a\\b",
+    "*",
+    "CON",
+];
+
+/// Relative and absolute paths, with some overweight to edge case
+/// components, like `.`, `..`, empty components, hidden files, spaces and
+/// non-ASCII characters. Components are separated with `/`. Empty components
+/// only occur after the first component, like in `a//b`, since a path can not
+/// start with one. Paths shrink toward relative paths of fewer and simpler
+/// components.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// monkey_test()
+///     .with_generator(gens::path::any())
+///     .assert_true(|path| path.join("x").ends_with("x"));
+/// ```
+pub fn any() -> BoxGen<PathBuf> {
+    parts().map(to_path, from_path)
+}
+
+/// Whether the path is absolute, and its components.
+fn parts() -> BoxGen<(bool, Vec<String>)> {
+    let edge_cases = EDGE_CASE_COMPONENTS
+        .iter()
+        .map(|component| component.to_string())
+        .collect::<Vec<_>>();
+
+    parts_of(crate::gens::mix_with_ratio(&[
+        (4, crate::gens::string::from_regex("[a-z]{1,8}")),
+        (1, crate::gens::pick_evenly(&edge_cases)),
+    ]))
+}
+
+/// Whether the path is absolute, and its components from given generator.
+/// The first component is never empty, since `["", "x"]` would be the
+/// relative path `/x`, that is read back as the absolute path `["x"]`.
+fn parts_of(components: BoxGen<String>) -> BoxGen<(bool, Vec<String>)> {
+    let is_absolute = crate::gens::bool::with_ratio(3, 1);
+
+    crate::gens::zip(is_absolute, crate::gens::vec::any(components)).filter(
+        |(_, components)| !components.first().is_some_and(String::is_empty),
+    )
+}
+
+fn to_path((is_absolute, components): (bool, Vec<String>)) -> PathBuf {
+    let root = if is_absolute { "/" } else { "" };
+    PathBuf::from(root.to_string() + &components.join("/"))
+}
+
+fn from_path(path: PathBuf) -> (bool, Vec<String>) {
+    let path = path.to_string_lossy();
+    let (is_absolute, relative) = match path.strip_prefix('/') {
+        Some(relative) => (true, relative),
+        None => (false, path.as_ref()),
+    };
+    let components = match relative {
+        "" => vec![],
+        relative => relative.split('/').map(str::to_string).collect(),
+    };
+    (is_absolute, components)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::monkey_test;
+
+    #[test]
+    fn parts_round_trip_through_path() {
+        monkey_test().with_generator(super::parts()).assert_eq(
+            |parts| parts.clone(),
+            |parts| super::from_path(super::to_path(parts)),
+        );
+    }
+
+    #[test]
+    fn parts_with_many_empty_components_round_trip_through_path() {
+        let components = crate::gens::pick_evenly(&[
+            "".to_string(),
+            "".to_string(),
+            "x".to_string(),
+        ]);
+
+        monkey_test()
+            .with_example_size(0..=4)
+            .with_generator(super::parts_of(components))
+            .assert_eq(
+                |parts| parts.clone(),
+                |parts| super::from_path(super::to_path(parts)),
+            );
+    }
+
+    #[test]
+    fn shrink_candidates_round_trip_through_path() {
+        monkey_test()
+            .with_example_size(0..=20)
+            .with_generator(super::any())
+            .assert_true(|path| {
+                super::any().shrinker().candidates(path).take(100).all(
+                    |candidate| {
+                        let parts = super::from_path(candidate.clone());
+                        super::to_path(parts) == candidate
+                    },
+                )
+            });
+    }
+}
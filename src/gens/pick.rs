@@ -1,11 +1,19 @@
 use crate::internal::sample_target::Ratio;
 use crate::internal::sample_target::SampleTarget;
 use crate::BoxGen;
-use rand::Rng;
+use crate::BoxShrink;
 use rand::SeedableRng;
+use std::cell::OnceCell;
+use std::collections::HashSet;
+use std::rc::Rc;
 
-/// Pick from given evenly distributed examples. Please note,
-/// the generator has no associated shrinker.
+/// Pick from given evenly distributed examples.
+///
+/// Picked examples shrink toward examples given earlier, treating the first
+/// example as the simplest one. An example is shrunk by shrinking the index of
+/// the first given example with the same debug representation, so examples
+/// need not be comparable. Given examples with the same debug representation
+/// count as one, and examples not among the given ones do not shrink.
 pub fn pick_evenly<E>(examples: &[E]) -> BoxGen<E>
 where
     E: Clone + 'static + core::fmt::Debug,
@@ -13,8 +21,9 @@ where
     pick_with_sample_target(SampleTarget::evenly(examples))
 }
 
-/// Pick one of given examples with frequencies by given ratios. Please note,
-/// the generator has no associated shrinker.
+/// Pick one of given examples with frequencies by given ratios. Picked
+/// examples shrink toward examples given earlier, see [pick_evenly]. Examples
+/// with a zero ratio are never picked, neither when shrinking.
 ///
 /// Example, where first value is picked 10% (= 1/(1+4+5))
 /// of the time, second value is picked 40% (= 4/(1+4+5)) of the time and
@@ -31,24 +40,92 @@ where
     pick_with_sample_target(SampleTarget::with_ratios(ratios_and_examples))
 }
 
+/// Pick one of given examples with frequencies by given floating point
+/// weights. Unlike [pick_with_ratio], any iterable collection of weights and
+/// examples is accepted and weights can be arbitrarily skewed. Picking is done
+/// in constant time, also from thousands of examples.
+///
+/// Picked examples shrink toward examples given earlier, see [pick_evenly].
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// // Word lengths following Zipf's law.
+/// let lengths = gens::pick_with_frequency(
+///     (1..=5000u32).map(|length| (1.0 / length as f64, length)),
+/// );
+/// ```
+pub fn pick_with_frequency<E>(
+    weights_and_examples: impl IntoIterator<Item = (f64, E)>,
+) -> BoxGen<E>
+where
+    E: Clone + 'static + core::fmt::Debug,
+{
+    pick_with_sample_target(SampleTarget::with_weights(weights_and_examples))
+}
+
 fn pick_with_sample_target<E>(sample_target: SampleTarget<E>) -> BoxGen<E>
 where
     E: Clone + 'static + core::fmt::Debug,
 {
+    let options = Rc::new(sample_target.targets().cloned().collect::<Vec<_>>());
+    let shrinker = toward_earlier_options(options);
+
     crate::gens::from_fn(move |seed, _size| {
-        let high = sample_target.sample_domain_max();
-        let distr = rand::distr::Uniform::new_inclusive(1usize, high).unwrap();
-        let rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
         let sample_target = sample_target.clone();
 
-        rng.sample_iter(distr).flat_map(move |sample| {
-            sample_target.target_from_sample(sample).cloned()
-        })
+        std::iter::from_fn(move || Some(sample_target.pick(&mut rng).clone()))
+    })
+    .with_shrinker(shrinker)
+}
+
+/// Shrink the index of the first option having the same debug representation
+/// as the original example, toward the first option. Options with the same
+/// debug representation as an earlier option are skipped, so that every
+/// candidate is tried only once.
+fn toward_earlier_options<E>(options: Rc<Vec<E>>) -> BoxShrink<E>
+where
+    E: Clone + 'static + core::fmt::Debug,
+{
+    let distinct = Rc::new(OnceCell::new());
+
+    crate::shrinks::from_fn(move |original: E| {
+        let distinct =
+            distinct.get_or_init(|| distinct_by_debug(&options)).clone();
+        let original = format!("{original:?}");
+        let index = distinct.iter().position(|(debug, _)| *debug == original);
+
+        index
+            .map(|index| {
+                crate::shrinks::int_to_zero::<usize>().candidates(index)
+            })
+            .into_iter()
+            .flatten()
+            .map(move |index| distinct[index].1.clone())
     })
 }
 
+/// Options with their debug representations, keeping only the first option of
+/// each representation.
+fn distinct_by_debug<E>(options: &[E]) -> Rc<Vec<(String, E)>>
+where
+    E: Clone + core::fmt::Debug,
+{
+    let mut seen = HashSet::new();
+
+    Rc::new(
+        options
+            .iter()
+            .map(|option| (format!("{option:?}"), option.clone()))
+            .filter(|(debug, _)| seen.insert(debug.clone()))
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod test {
+    use crate::testing::assert_iter_eq;
     use crate::testing::distribution::assert_generator_has_distribution_within_percent;
     use crate::testing::distribution::distribution_from_pairs;
     use crate::testing::distribution::even_distribution_of;
@@ -87,6 +164,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn pick_with_ratio_accepts_ratios_larger_than_u8() {
+        assert_generator_has_distribution_within_percent(
+            super::pick_with_ratio(&[(1000, 'a'), (3000, 'b')]),
+            distribution_from_pairs(&[(1, 'a'), (3, 'b')]),
+            1.0,
+        );
+    }
+
+    #[test]
+    fn pick_with_frequency_follow_given_weights() {
+        assert_generator_has_distribution_within_percent(
+            super::pick_with_frequency(
+                "abc".chars().zip([0.5, 0.25, 0.25]).map(|(c, w)| (w, c)),
+            ),
+            distribution_from_pairs(&[(2, 'a'), (1, 'b'), (1, 'c')]),
+            1.0,
+        );
+    }
+
     #[test]
     fn pick_evenly_is_evenly_distributed() {
         assert_generator_has_distribution_within_percent(
@@ -95,4 +192,86 @@ mod test {
             1.0,
         );
     }
+
+    #[test]
+    fn pick_shrinks_toward_earlier_options() {
+        let methods = super::pick_evenly(&["GET", "POST", "PUT", "DELETE"]);
+        let delete = picked(&methods, "DELETE");
+
+        assert_iter_eq(
+            methods.shrinker().candidates(delete),
+            vec!["GET", "PUT", "POST"],
+            "should shrink toward first option, like shrinking the index of \
+            the option toward zero",
+        );
+        assert_iter_eq(
+            methods.shrinker().candidates("GET"),
+            Vec::<&str>::new(),
+            "first option should not shrink",
+        );
+    }
+
+    #[test]
+    fn pick_shrinks_skipping_options_with_zero_ratio() {
+        let numbers = super::pick_with_ratio(&[(1, 7), (0, 8), (1, 9)]);
+
+        assert_iter_eq(
+            numbers.shrinker().candidates(9),
+            vec![7],
+            "should shrink toward earlier option, skipping options with \
+            zero ratio",
+        );
+    }
+
+    #[test]
+    fn pick_shrinks_duplicated_options_toward_first_occurrence() {
+        let letters = super::pick_evenly(&['a', 'b', 'a', 'c']);
+
+        assert_iter_eq(
+            letters.shrinker().candidates('c'),
+            vec!['a', 'b'],
+            "should shrink from the index of the option, trying each \
+            distinct option once",
+        );
+        assert_iter_eq(
+            letters.shrinker().candidates('a'),
+            Vec::<char>::new(),
+            "should treat equal options as the first of them",
+        );
+    }
+
+    #[test]
+    fn pick_shrinks_candidates_further() {
+        let numbers = super::pick_evenly(&[1, 2, 3, 4, 5]);
+        let five = picked(&numbers, 5);
+        let three = numbers
+            .shrinker()
+            .candidates(five)
+            .find(|n| *n == 3)
+            .unwrap();
+
+        assert_iter_eq(
+            numbers.shrinker().candidates(three),
+            vec![1, 2],
+            "should remember index of candidate when shrinking it further",
+        );
+    }
+
+    #[test]
+    fn pick_does_not_shrink_examples_it_did_not_pick() {
+        let methods = super::pick_evenly(&["GET", "POST"]);
+
+        assert_iter_eq(
+            methods.shrinker().candidates("PATCH"),
+            Vec::<&str>::new(),
+            "should have no candidates for example not among options",
+        );
+    }
+
+    fn picked<E>(gen_: &crate::BoxGen<E>, example: E) -> E
+    where
+        E: Clone + PartialEq + 'static,
+    {
+        gen_.examples(0, 0..=100).find(|e| *e == example).unwrap()
+    }
 }
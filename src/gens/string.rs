@@ -1,7 +1,18 @@
 //! Generators for String type.
 
+use crate::internal::example_memory::ExampleMemory;
+use crate::internal::regex::Regex;
+use crate::internal::regex::MAX_UNBOUNDED_REPEAT;
+use crate::internal::sample_target::Ratio;
+use crate::internal::sample_target::SampleTarget;
+use crate::internal::unicode::Items;
+use crate::internal::unicode::EDGE_CASES;
+use crate::internal::unicode::NFC_AND_NFD;
 use crate::BoxGen;
+use crate::BoxShrink;
 use crate::MapWithGen;
+use rand::Rng;
+use rand::SeedableRng;
 
 /// Build String generator from char generator.
 fn strings_from_chars(chars: BoxGen<char>) -> BoxGen<String> {
@@ -71,3 +82,217 @@ pub fn ascii_printable() -> BoxGen<String> {
 pub fn ascii() -> BoxGen<String> {
     strings_from_chars(crate::gens::char::ascii())
 }
+
+/// Strings weighted toward unicode edge cases that often break text
+/// processing.
+///
+/// Besides printable ASCII characters, strings are built from combining
+/// marks, letters with tricky case mapping or normalization, emoji with
+/// modifiers and ZWJ sequences, right-to-left text and bidirectional control
+/// characters like right-to-left overrides, invisible characters like byte
+/// order marks, and code points at boundaries like the ones adjacent to the
+/// surrogate range. Each of these categories is used about a tenth of the
+/// time.
+///
+/// Strings are shrunk by removing and simplifying the parts they are built
+/// from, so that emoji sequences and similar are kept whole while shrinking.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// monkey_test()
+///     .with_generator(gens::string::unicode_edge_cases())
+///     .assert_true(|s| s.chars().rev().count() == s.chars().count());
+/// ```
+pub fn unicode_edge_cases() -> BoxGen<String> {
+    let categories = EDGE_CASES
+        .iter()
+        .map(|(ratio, items)| (*ratio, items.len()))
+        .collect::<Vec<_>>();
+
+    from_pieces(&categories, |pieces| {
+        pieces
+            .iter()
+            .map(|&(category, item)| EDGE_CASES[category].1.get(item))
+            .collect()
+    })
+}
+
+/// Pairs of canonically equivalent strings, where the first string is in
+/// unicode normalization form NFC and the second in form NFD.
+///
+/// Strings mix printable ASCII characters with characters from a built-in
+/// table of precomposed characters and their decompositions, like `"é"` and
+/// `"e\u{301}"`, including characters with several combining marks in
+/// canonical order and Hangul syllables.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// monkey_test()
+///     .with_generator(gens::string::nfc_and_nfd())
+///     .assert_true(|(nfc, nfd)| nfc.chars().count() <= nfd.chars().count());
+/// ```
+pub fn nfc_and_nfd() -> BoxGen<(String, String)> {
+    let categories = [(1, Items::Ascii.len()), (1, NFC_AND_NFD.len())];
+
+    from_pieces(&categories, |pieces| {
+        let mut nfc = String::new();
+        let mut nfd = String::new();
+        for &(category, item) in pieces {
+            if category == 0 {
+                nfc += &Items::Ascii.get(item);
+                nfd += &Items::Ascii.get(item);
+            } else {
+                let (c, d) = NFC_AND_NFD[item.min(NFC_AND_NFD.len() - 1)];
+                nfc += c;
+                nfd += d;
+            }
+        }
+        (nfc, nfd)
+    })
+}
+
+/// Build generator of examples rendered from pieces, where each piece is an
+/// index of a category, picked according to given ratios, and an index of an
+/// item among the given number of items in that category.
+///
+/// Examples are shrunk by shrinking the pieces they were rendered from,
+/// remembered when generating them.
+fn from_pieces<R>(
+    categories: &[(Ratio, usize)],
+    render: fn(&[(usize, usize)]) -> R,
+) -> BoxGen<R>
+where
+    R: Clone + core::fmt::Debug + std::hash::Hash + Eq + 'static,
+{
+    let item_counts = categories.iter().map(|c| c.1).collect::<Vec<_>>();
+    let target = SampleTarget::with_ratios(
+        &categories
+            .iter()
+            .enumerate()
+            .map(|(index, (ratio, _))| (*ratio, index))
+            .collect::<Vec<_>>(),
+    );
+    let piece = crate::gens::from_fn(move |seed, _size| {
+        let target = target.clone();
+        let item_counts = item_counts.clone();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+
+        std::iter::from_fn(move || {
+            let category = *target.pick(&mut rng);
+            Some((category, rng.random_range(0..item_counts[category])))
+        })
+    })
+    .with_shrinker(crate::shrinks::zip(
+        crate::shrinks::int_to_zero(),
+        crate::shrinks::int_to_zero(),
+    ));
+    let pieces = crate::gens::vec::any(piece);
+    let pieces_shrinker = pieces.shrinker();
+    let memory = ExampleMemory::default();
+
+    crate::gens::from_fn({
+        let memory = memory.clone();
+        move |seed, size| {
+            let memory = memory.clone();
+            pieces.examples(seed, size).map(move |pieces| {
+                let example = render(&pieces);
+                memory.remember(&example, pieces);
+                example
+            })
+        }
+    })
+    .with_shrinker(crate::shrinks::from_fn(move |original: R| {
+        let memory = memory.clone();
+        let pieces = memory.recall(&original);
+
+        pieces
+            .map(|pieces| pieces_shrinker.candidates(pieces))
+            .into_iter()
+            .flatten()
+            .map(move |pieces| {
+                let candidate = render(&pieces);
+                memory.remember(&candidate, pieces);
+                candidate
+            })
+    }))
+}
+
+/// Strings matching given regular expression.
+///
+/// Supported syntax is literal characters, `.`, character classes like
+/// `[a-z_]` and `[^0-9]`, the escapes `\d`, `\w`, `\s`, `\D`, `\W` and `\S`,
+/// groups `(...)` and `(?:...)`, alternation `|` and the quantifiers `*`, `+`,
+/// `?`, `{n}`, `{n,}` and `{n,m}`. The anchors `^` and `$` are ignored, since
+/// whole strings are generated. Negated classes and `.` only match printable
+/// ASCII characters. Unbounded quantifiers repeat at most 32 times beyond their
+/// minimum, and no more times than the upper bound of the example size.
+///
+/// Strings are shrunk by shrinking the choices made when generating them,
+/// like which branch of an alternation or how many repetitions to use, so
+/// that all shrink candidates also match the regular expression.
+/// Characters of a character class shrink toward the simplest character in
+/// the class, see [crate::shrinks::char].
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// monkey_test()
+///     .with_generator(gens::string::from_regex("[a-z]{3,8}@[a-z]+\\.com"))
+///     .assert_true(|email| email.ends_with(".com"));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the regular expression is invalid or uses unsupported syntax.
+pub fn from_regex(pattern: &str) -> BoxGen<String> {
+    let regex = Regex::parse(pattern).unwrap_or_else(|reason| {
+        panic!("Invalid regular expression {pattern:?}: {reason}")
+    });
+    let memory = ExampleMemory::default();
+    let shrinker = regex_shrinker(regex.clone(), memory.clone());
+
+    crate::gens::from_fn(move |seed, size| {
+        let regex = regex.clone();
+        let memory = memory.clone();
+        let max_repeat = (*size.end()).min(MAX_UNBOUNDED_REPEAT);
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(seed);
+
+        std::iter::from_fn(move || {
+            let (example, choices) = regex.generate(&mut rng, max_repeat);
+            memory.remember(&example, choices);
+            Some(example)
+        })
+    })
+    .with_shrinker(shrinker)
+}
+
+/// Shrink strings by shrinking the choices producing them. The choices are
+/// remembered when generating strings, or else found by matching the string
+/// against the regular expression.
+fn regex_shrinker(
+    regex: Regex,
+    memory: ExampleMemory<String, Vec<u32>>,
+) -> BoxShrink<String> {
+    let choices_shrinker =
+        crate::shrinks::vec::positional(crate::shrinks::int_to_zero::<u32>());
+
+    crate::shrinks::from_fn(move |original: String| {
+        let choices = memory
+            .recall(&original)
+            .or_else(|| regex.choices_of(&original))
+            .unwrap_or_default();
+        let regex = regex.clone();
+        let memory = memory.clone();
+
+        choices_shrinker
+            .candidates(choices)
+            .map(move |choices| {
+                let candidate = regex.replay(&choices);
+                memory.remember(&candidate, choices);
+                candidate
+            })
+            .filter(move |candidate| *candidate != original)
+    })
+}
@@ -0,0 +1,77 @@
+//! Generators for values of type [std::time::SystemTime].
+
+use crate::BoxGen;
+use crate::MapWithGen;
+use std::ops::Bound;
+use std::ops::RangeBounds;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+/// Roughly uniformly distributed points in time from the UNIX epoch,
+/// 1970-01-01, to the end of 32 bit unsigned UNIX time in year 2106. Points in
+/// time shrink toward the UNIX epoch.
+pub fn any() -> BoxGen<SystemTime> {
+    ranged(..)
+}
+
+/// Roughly uniformly distributed points in time in given range, with some
+/// overweight to the extremes of the range. An unbounded start of the range
+/// is the UNIX epoch and an unbounded end is the end of 32 bit unsigned UNIX
+/// time, in year 2106. Points in time shrink toward the UNIX epoch, or the
+/// point in range nearest to it.
+///
+/// ```rust
+/// use monkey_test::*;
+/// use std::time::Duration;
+/// use std::time::SystemTime;
+/// use std::time::UNIX_EPOCH;
+///
+/// fn year_2000() -> SystemTime {
+///     UNIX_EPOCH + Duration::from_secs(946_684_800)
+/// }
+///
+/// monkey_test()
+///     .with_generator(gens::system_time::ranged(year_2000()..))
+///     .assert_true(|t| t >= year_2000());
+/// ```
+pub fn ranged<B>(bounds: B) -> BoxGen<SystemTime>
+where
+    B: RangeBounds<SystemTime>,
+{
+    let start = match bounds.start_bound() {
+        Bound::Included(t) => Bound::Included(to_nanos(*t)),
+        Bound::Excluded(t) => Bound::Excluded(to_nanos(*t)),
+        Bound::Unbounded => Bound::Included(0),
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(t) => Bound::Included(to_nanos(*t)),
+        Bound::Excluded(t) => Bound::Excluded(to_nanos(*t)),
+        Bound::Unbounded => {
+            Bound::Included(to_nanos(UNIX_EPOCH + max_unsigned_unix_time()))
+        }
+    };
+
+    crate::gens::i128::ranged((start, end)).map(from_nanos, to_nanos)
+}
+
+fn max_unsigned_unix_time() -> Duration {
+    Duration::from_secs(u32::MAX.into())
+}
+
+/// Nanoseconds since the UNIX epoch, negative before the epoch.
+fn to_nanos(time: SystemTime) -> i128 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => after.as_nanos() as i128,
+        Err(before) => -(before.duration().as_nanos() as i128),
+    }
+}
+
+fn from_nanos(nanos: i128) -> SystemTime {
+    let duration = crate::gens::duration::from_nanos(nanos.unsigned_abs());
+    if nanos < 0 {
+        UNIX_EPOCH - duration
+    } else {
+        UNIX_EPOCH + duration
+    }
+}
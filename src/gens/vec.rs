@@ -16,15 +16,15 @@
 //!     actual_examples,
 //!     vec![
 //!       vec![9, 9, 9],
-//!       vec![9, 9, 9],
 //!       vec![9, 9],
 //!       vec![9],
 //!       vec![9, 9],
-//!       vec![9, 9, 9],
 //!       vec![9],
 //!       vec![9, 9, 9],
 //!       vec![9],
 //!       vec![9, 9, 9],
+//!       vec![9, 9, 9],
+//!       vec![9, 9, 9],
 //!       vec![9],
 //!       vec![9, 9, 9],
 //!       vec![],
@@ -32,7 +32,7 @@
 //!       vec![9, 9, 9],
 //!       vec![9, 9, 9],
 //!       vec![9, 9],
-//!       vec![9, 9],
+//!       vec![],
 //!       vec![9, 9],
 //!       vec![9, 9]
 //!     ]
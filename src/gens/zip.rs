@@ -1,4 +1,5 @@
 use crate::BoxGen;
+use crate::MapWithGen;
 
 /// Combine two generators together element wise into generator of tuples.
 ///
@@ -16,6 +17,8 @@ use crate::BoxGen;
 /// // Shorthand way to do the same thing.
 /// let tuples2: BoxGen<(u8, char)> = bytes2.zip(chars2);
 /// ```
+///
+/// For tuples with more parts, see [zip_3], [zip_4], [zip_5] and [zip_6].
 pub fn zip<E0, E1>(g0: BoxGen<E0>, g1: BoxGen<E1>) -> BoxGen<(E0, E1)>
 where
     E0: Clone + 'static,
@@ -37,6 +40,126 @@ where
     .with_shrinker(crate::shrinks::zip(s0, s1))
 }
 
+// The generators of more than two parts generate examples by nesting pairs,
+// so that a given seed generates the same examples as in earlier versions.
+// Only the shrinkers are flat, treating all parts alike.
+
+/// Combine 3 generators together element wise into generator of tuples.
+/// See [zip].
+pub fn zip_3<E0, E1, E2>(
+    g0: BoxGen<E0>,
+    g1: BoxGen<E1>,
+    g2: BoxGen<E2>,
+) -> BoxGen<(E0, E1, E2)>
+where
+    E0: Clone + 'static,
+    E1: Clone + 'static,
+    E2: Clone + 'static,
+{
+    let shrinker =
+        crate::shrinks::zip_3(g0.shrinker(), g1.shrinker(), g2.shrinker());
+
+    zip(zip(g0, g1), g2)
+        .map(|((e0, e1), e2)| (e0, e1, e2), |(e0, e1, e2)| ((e0, e1), e2))
+        .with_shrinker(shrinker)
+}
+
+/// Combine 4 generators together element wise into generator of tuples.
+/// See [zip].
+pub fn zip_4<E0, E1, E2, E3>(
+    g0: BoxGen<E0>,
+    g1: BoxGen<E1>,
+    g2: BoxGen<E2>,
+    g3: BoxGen<E3>,
+) -> BoxGen<(E0, E1, E2, E3)>
+where
+    E0: Clone + 'static,
+    E1: Clone + 'static,
+    E2: Clone + 'static,
+    E3: Clone + 'static,
+{
+    let shrinker = crate::shrinks::zip_4(
+        g0.shrinker(),
+        g1.shrinker(),
+        g2.shrinker(),
+        g3.shrinker(),
+    );
+
+    zip(zip(g0, g1), zip(g2, g3))
+        .map(
+            |((e0, e1), (e2, e3))| (e0, e1, e2, e3),
+            |(e0, e1, e2, e3)| ((e0, e1), (e2, e3)),
+        )
+        .with_shrinker(shrinker)
+}
+
+/// Combine 5 generators together element wise into generator of tuples.
+/// See [zip].
+pub fn zip_5<E0, E1, E2, E3, E4>(
+    g0: BoxGen<E0>,
+    g1: BoxGen<E1>,
+    g2: BoxGen<E2>,
+    g3: BoxGen<E3>,
+    g4: BoxGen<E4>,
+) -> BoxGen<(E0, E1, E2, E3, E4)>
+where
+    E0: Clone + 'static,
+    E1: Clone + 'static,
+    E2: Clone + 'static,
+    E3: Clone + 'static,
+    E4: Clone + 'static,
+{
+    let shrinker = crate::shrinks::zip_5(
+        g0.shrinker(),
+        g1.shrinker(),
+        g2.shrinker(),
+        g3.shrinker(),
+        g4.shrinker(),
+    );
+
+    zip(zip(g0, g1), zip_3(g2, g3, g4))
+        .map(
+            |((e0, e1), (e2, e3, e4))| (e0, e1, e2, e3, e4),
+            |(e0, e1, e2, e3, e4)| ((e0, e1), (e2, e3, e4)),
+        )
+        .with_shrinker(shrinker)
+}
+
+/// Combine 6 generators together element wise into generator of tuples.
+/// See [zip].
+pub fn zip_6<E0, E1, E2, E3, E4, E5>(
+    g0: BoxGen<E0>,
+    g1: BoxGen<E1>,
+    g2: BoxGen<E2>,
+    g3: BoxGen<E3>,
+    g4: BoxGen<E4>,
+    g5: BoxGen<E5>,
+) -> BoxGen<(E0, E1, E2, E3, E4, E5)>
+where
+    E0: Clone + 'static,
+    E1: Clone + 'static,
+    E2: Clone + 'static,
+    E3: Clone + 'static,
+    E4: Clone + 'static,
+    E5: Clone + 'static,
+{
+    let shrinker = crate::shrinks::zip_6(
+        g0.shrinker(),
+        g1.shrinker(),
+        g2.shrinker(),
+        g3.shrinker(),
+        g4.shrinker(),
+        g5.shrinker(),
+    );
+
+    zip(zip_3(g0, g1, g2), zip_3(g3, g4, g5))
+        .map(
+            |((e0, e1, e2), (e3, e4, e5))| (e0, e1, e2, e3, e4, e5),
+            |(e0, e1, e2, e3, e4, e5)| ((e0, e1, e2), (e3, e4, e5)),
+        )
+        .with_shrinker(shrinker)
+}
+
 #[cfg(test)]
 mod test {
     use crate::gens::fixed;
@@ -112,4 +235,28 @@ mod test {
         let tuples = super::zip(same_gen.clone(), same_gen);
         assert! {tuples.examples(1234,0..=1000).take(100).any(|(a,b)| a!= b)}
     }
+
+    /// Keep examples of a given seed the same as when tuples of more parts
+    /// were composed of nested pairs.
+    #[test]
+    fn tuples_of_many_parts_have_same_examples_as_nested_pairs() {
+        let g = crate::gens::u8::any;
+        let pairs = super::zip(
+            super::zip(super::zip(g(), g()), g()),
+            super::zip(super::zip(g(), g()), g()),
+        );
+        let tuples = super::zip_6(g(), g(), g(), g(), g(), g());
+
+        assert_eq!(
+            tuples
+                .examples(1234, 0..=1000)
+                .take(100)
+                .collect::<Vec<_>>(),
+            pairs
+                .examples(1234, 0..=1000)
+                .take(100)
+                .map(|(((a, b), c), ((d, e), f))| (a, b, c, d, e, f))
+                .collect::<Vec<_>>()
+        );
+    }
 }
@@ -1,2 +1,10 @@
+pub mod bnf;
+pub mod char_rank;
+pub mod diff;
+pub mod example_memory;
+pub mod executor;
 pub mod int_bounds;
+pub mod isolation;
+pub mod regex;
 pub mod sample_target;
+pub mod unicode;
@@ -0,0 +1,363 @@
+//! Parser of grammars in BNF, with some EBNF extensions, into rules of
+//! alternative symbol sequences.
+//!
+//! EBNF constructs are rewritten into plain rules of generated nonterminals,
+//! named after the rule they occur in, like `<sum#1>`:
+//!
+//! * `[x]` and `x?` become `<r> ::= | x`
+//! * `{x}` and `x*` become `<r> ::= | x <r>`
+//! * `x+` becomes `<r> ::= x | x <r>`
+//! * `(x)` becomes `<r> ::= x`
+
+use crate::gens::grammar::Symbol;
+
+/// Rules as nonterminal names and their alternatives, in order of appearance.
+pub type Rules = Vec<(String, Vec<Vec<Symbol>>)>;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Nonterminal(String),
+    Terminal(String),
+    Defines,
+    Char(char),
+}
+
+/// Parse grammar text into rules.
+pub fn parse(text: &str) -> Result<Rules, String> {
+    let tokens = tokenize(text)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        rules: Vec::new(),
+        generated: 0,
+    };
+
+    while parser.pos < parser.tokens.len() {
+        parser.rule()?;
+    }
+
+    if parser.rules.is_empty() {
+        return Err("no rules found".to_string());
+    }
+    Ok(parser.rules)
+}
+
+fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, String> {
+    let mut tokens = Vec::new();
+
+    for (line_index, line) in text.lines().enumerate() {
+        let line_number = line_index + 1;
+        if line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                c if c.is_whitespace() => continue,
+                '<' => {
+                    let name =
+                        take_until(&mut chars, '>').ok_or_else(|| {
+                            format!(
+                                "unclosed nonterminal on line {line_number}"
+                            )
+                        })?;
+                    Token::Nonterminal(name)
+                }
+                '"' | '\'' => {
+                    let text = take_quoted(&mut chars, c).ok_or_else(|| {
+                        format!("unclosed terminal on line {line_number}")
+                    })?;
+                    Token::Terminal(text)
+                }
+                ':' => {
+                    if chars.next() == Some(':') && chars.next() == Some('=') {
+                        Token::Defines
+                    } else {
+                        return Err(format!(
+                            "expected '::=' on line {line_number}"
+                        ));
+                    }
+                }
+                '|' | '[' | ']' | '{' | '}' | '(' | ')' | '?' | '*' | '+' => {
+                    Token::Char(c)
+                }
+                c => {
+                    return Err(format!(
+                        "unexpected {c:?} on line {line_number}"
+                    ))
+                }
+            };
+            tokens.push((line_number, token));
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn take_until(
+    chars: &mut impl Iterator<Item = char>,
+    end: char,
+) -> Option<String> {
+    let mut result = String::new();
+    for c in chars {
+        if c == end {
+            return Some(result);
+        }
+        result.push(c);
+    }
+    None
+}
+
+fn take_quoted(
+    chars: &mut impl Iterator<Item = char>,
+    quote: char,
+) -> Option<String> {
+    let mut result = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.push(match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                other => other,
+            }),
+            c if c == quote => return Some(result),
+            c => result.push(c),
+        }
+    }
+    None
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    rules: Rules,
+    generated: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, token)| token)
+    }
+
+    fn line(&self) -> String {
+        match self.tokens.get(self.pos) {
+            Some((line, _)) => format!("line {line}"),
+            None => "end of grammar".to_string(),
+        }
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_rule_start(&self) -> bool {
+        matches!(self.peek(), Some(Token::Nonterminal(_)))
+            && self.tokens.get(self.pos + 1).map(|(_, t)| t)
+                == Some(&Token::Defines)
+    }
+
+    fn rule(&mut self) -> Result<(), String> {
+        let name = match self.peek() {
+            Some(Token::Nonterminal(name)) if self.is_rule_start() => {
+                name.clone()
+            }
+            _ => return Err(format!("expected rule on {}", self.line())),
+        };
+        self.pos += 2;
+
+        // Reserve the position of the rule, before any generated rules.
+        let index = self.rules.len();
+        self.rules.push((name.clone(), Vec::new()));
+        let alternatives = self.alternatives(&name)?;
+        self.rules[index].1 = alternatives;
+        Ok(())
+    }
+
+    fn alternatives(&mut self, rule: &str) -> Result<Vec<Vec<Symbol>>, String> {
+        let mut alternatives = vec![self.sequence(rule)?];
+        while self.eat(&Token::Char('|')) {
+            alternatives.push(self.sequence(rule)?);
+        }
+        Ok(alternatives)
+    }
+
+    fn sequence(&mut self, rule: &str) -> Result<Vec<Symbol>, String> {
+        let mut symbols = Vec::new();
+        loop {
+            let symbol = match self.peek().cloned() {
+                None => break,
+                Some(Token::Nonterminal(_)) if self.is_rule_start() => break,
+                Some(Token::Nonterminal(name)) => {
+                    self.pos += 1;
+                    Symbol::Nonterminal(name)
+                }
+                Some(Token::Terminal(text)) => {
+                    self.pos += 1;
+                    Symbol::Terminal(text)
+                }
+                Some(Token::Char(open @ ('[' | '{' | '('))) => {
+                    self.pos += 1;
+                    self.group(rule, open)?
+                }
+                Some(Token::Char('|' | ']' | '}' | ')')) => break,
+                Some(token) => {
+                    return Err(format!(
+                        "unexpected {token:?} on {}",
+                        self.line()
+                    ))
+                }
+            };
+            symbols.push(self.postfix(rule, symbol));
+        }
+        Ok(symbols)
+    }
+
+    fn group(&mut self, rule: &str, open: char) -> Result<Symbol, String> {
+        let line = self.line();
+        let alternatives = self.alternatives(rule)?;
+        let close = match open {
+            '[' => ']',
+            '{' => '}',
+            _ => ')',
+        };
+        if !self.eat(&Token::Char(close)) {
+            return Err(format!("unclosed '{open}' on {line}"));
+        }
+
+        let grouped = self.generate_rule(rule, alternatives);
+        Ok(match open {
+            '[' => self.optional(rule, grouped),
+            '{' => self.repeated(rule, grouped, false),
+            _ => grouped,
+        })
+    }
+
+    fn postfix(&mut self, rule: &str, symbol: Symbol) -> Symbol {
+        let mut symbol = symbol;
+        loop {
+            symbol = if self.eat(&Token::Char('?')) {
+                self.optional(rule, symbol)
+            } else if self.eat(&Token::Char('*')) {
+                self.repeated(rule, symbol, false)
+            } else if self.eat(&Token::Char('+')) {
+                self.repeated(rule, symbol, true)
+            } else {
+                return symbol;
+            }
+        }
+    }
+
+    fn optional(&mut self, rule: &str, symbol: Symbol) -> Symbol {
+        self.generate_rule(rule, vec![vec![], vec![symbol]])
+    }
+
+    fn repeated(
+        &mut self,
+        rule: &str,
+        symbol: Symbol,
+        at_least_once: bool,
+    ) -> Symbol {
+        self.generated += 1;
+        let name = format!("{rule}#{}", self.generated);
+        let itself = Symbol::Nonterminal(name.clone());
+        let first = if at_least_once {
+            vec![symbol.clone()]
+        } else {
+            vec![]
+        };
+        self.rules
+            .push((name, vec![first, vec![symbol, itself.clone()]]));
+        itself
+    }
+
+    fn generate_rule(
+        &mut self,
+        rule: &str,
+        alternatives: Vec<Vec<Symbol>>,
+    ) -> Symbol {
+        self.generated += 1;
+        let name = format!("{rule}#{}", self.generated);
+        self.rules.push((name.clone(), alternatives));
+        Symbol::Nonterminal(name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse;
+    use crate::gens::grammar::nonterminal as n;
+    use crate::gens::grammar::terminal as t;
+
+    #[test]
+    fn parses_plain_bnf() {
+        let rules = parse(
+            r#"
+            # Comment
+            <sum> ::= <num> | <num> "+" <sum>
+            <num> ::= '1' | "2"
+                    | "\"3\""
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rules,
+            vec![
+                (
+                    "sum".to_string(),
+                    vec![vec![n("num")], vec![n("num"), t("+"), n("sum")]]
+                ),
+                (
+                    "num".to_string(),
+                    vec![vec![t("1")], vec![t("2")], vec![t("\"3\"")]]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn rewrites_ebnf_into_generated_rules() {
+        let rules = parse(r#"<list> ::= "[" [ <x> { "," <x> } ] "]" <x>+"#);
+
+        assert_eq!(
+            rules.unwrap(),
+            vec![
+                (
+                    "list".to_string(),
+                    vec![vec![t("["), n("list#4"), t("]"), n("list#5")]]
+                ),
+                ("list#1".to_string(), vec![vec![t(","), n("x")]]),
+                (
+                    "list#2".to_string(),
+                    vec![vec![], vec![n("list#1"), n("list#2")]]
+                ),
+                ("list#3".to_string(), vec![vec![n("x"), n("list#2")]]),
+                ("list#4".to_string(), vec![vec![], vec![n("list#3")]]),
+                (
+                    "list#5".to_string(),
+                    vec![vec![n("x")], vec![n("x"), n("list#5")]]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_grammar() {
+        let error = |text| parse(text).unwrap_err();
+
+        assert_eq!(error(""), "no rules found");
+        assert_eq!(error("<a> := 'x'"), "expected '::=' on line 1");
+        assert_eq!(error("<a ::= 'x'"), "unclosed nonterminal on line 1");
+        assert_eq!(error("<a> ::= 'x"), "unclosed terminal on line 1");
+        assert_eq!(error("<a> ::= ( 'x'\n"), "unclosed '(' on line 1");
+        assert_eq!(error("'x'"), "expected rule on line 1");
+        assert_eq!(error("<a> ::= x"), "unexpected 'x' on line 1");
+    }
+}
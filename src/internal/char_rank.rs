@@ -0,0 +1,84 @@
+//! Ordering of chars by simplicity, used when shrinking chars.
+//!
+//! Lower case letters are the simplest chars, followed by upper case letters,
+//! digits, space, other printable ASCII chars and ASCII control chars. All
+//! other chars follow in code point order.
+
+/// Highest rank of any char.
+pub const MAX_RANK: u32 = char::MAX as u32 - SURROGATE_COUNT;
+
+const SURROGATE_START: u32 = 0xD800;
+const SURROGATE_COUNT: u32 = 0x800;
+
+/// Rank of given char, where a lower rank is a simpler char.
+pub fn rank(c: char) -> u32 {
+    let code = c as u32;
+    match c {
+        'a'..='z' => code - 'a' as u32,
+        'A'..='Z' => 26 + code - 'A' as u32,
+        '0'..='9' => 52 + code - '0' as u32,
+        ' ' => 62,
+        '!'..='/' => 63 + code - '!' as u32,
+        ':'..='@' => 78 + code - ':' as u32,
+        '['..='`' => 85 + code - '[' as u32,
+        '{'..='~' => 91 + code - '{' as u32,
+        '\0'..='\x1f' => 95 + code,
+        '\x7f' => 127,
+        _ if code < SURROGATE_START => code,
+        _ => code - SURROGATE_COUNT,
+    }
+}
+
+/// Char of given rank, if any.
+pub fn from_rank(rank: u32) -> Option<char> {
+    let (base, offset) = match rank {
+        0..=25 => ('a', rank),
+        26..=51 => ('A', rank - 26),
+        52..=61 => ('0', rank - 52),
+        62 => (' ', 0),
+        63..=77 => ('!', rank - 63),
+        78..=84 => (':', rank - 78),
+        85..=90 => ('[', rank - 85),
+        91..=94 => ('{', rank - 91),
+        95..=126 => ('\0', rank - 95),
+        127 => ('\x7f', 0),
+        _ if rank < SURROGATE_START => ('\u{80}', rank - 128),
+        _ => ('\u{e000}', rank - SURROGATE_START),
+    };
+    char::from_u32(base as u32 + offset)
+}
+
+#[cfg(test)]
+mod test {
+    use super::from_rank;
+    use super::rank;
+    use super::MAX_RANK;
+
+    #[test]
+    fn ranks_are_unique_and_reversible() {
+        let mut ranks = (0..=char::MAX as u32)
+            .filter_map(char::from_u32)
+            .map(|c| {
+                assert_eq!(from_rank(rank(c)), Some(c));
+                rank(c)
+            })
+            .collect::<Vec<_>>();
+        ranks.sort();
+
+        assert_eq!(ranks, (0..=MAX_RANK).collect::<Vec<_>>());
+        assert_eq!(from_rank(MAX_RANK + 1), None);
+    }
+
+    #[test]
+    fn simple_chars_have_low_ranks() {
+        let simplest = (0..=10).filter_map(from_rank).collect::<String>();
+        assert_eq!(simplest, "abcdefghijk");
+
+        assert!(rank('z') < rank('A'));
+        assert!(rank('Z') < rank('0'));
+        assert!(rank('9') < rank(' '));
+        assert!(rank(' ') < rank('!'));
+        assert!(rank('~') < rank('\0'));
+        assert!(rank('\x7f') < rank('ä'));
+    }
+}
@@ -0,0 +1,273 @@
+//! Line based diff of pretty-printed values, used for explaining why two
+//! values are not equal.
+
+use std::fmt::Debug;
+
+/// Maximum number of line pairs to compare with the longest common
+/// subsequence algorithm. Larger inputs only get the common prefix and suffix
+/// trimmed, to keep failure reporting fast.
+const MAX_LCS_CELLS: usize = 1_000_000;
+
+/// Explain the difference between two values by a diff of their
+/// pretty-printed `{:#?}` debug representations.
+///
+/// Returns an empty string if both values are printed on a single line, since
+/// the values are then short enough to compare by eye. Otherwise, the returned
+/// text starts with an empty line, so it can be appended to an existing
+/// single line failure reason.
+pub fn explain_difference<D>(expected: &D, actual: &D) -> String
+where
+    D: Debug,
+{
+    let expected = format!("{expected:#?}");
+    let actual = format!("{actual:#?}");
+
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return String::new();
+    }
+
+    let expected_lines = expected.lines().collect::<Vec<_>>();
+    let actual_lines = actual.lines().collect::<Vec<_>>();
+    let expected_paths = line_paths(&expected_lines);
+    let actual_paths = line_paths(&actual_lines);
+
+    let mut paths = Vec::<String>::new();
+    let mut add_path = |path: &String| {
+        let path = if path.is_empty() { "<root>" } else { path };
+        if !paths.iter().any(|p| p == path) {
+            paths.push(path.to_string());
+        }
+    };
+
+    let mut diff_text = String::new();
+    for line in diff_lines(&expected_lines, &actual_lines) {
+        match line {
+            DiffLine::Same(e) => {
+                diff_text += &format!("\n  {}", expected_lines[e])
+            }
+            DiffLine::Removed(e) => {
+                add_path(&expected_paths[e]);
+                diff_text += &format!("\n- {}", expected_lines[e])
+            }
+            DiffLine::Added(a) => {
+                add_path(&actual_paths[a]);
+                diff_text += &format!("\n+ {}", actual_lines[a])
+            }
+        }
+    }
+
+    format!(
+        "\n\nDiffering paths: {}\nDiff (- expected, + actual):{diff_text}",
+        paths.join(", ")
+    )
+}
+
+/// A single line in a diff, referring to line index in expected text for
+/// removed and unchanged lines and in actual text for added lines.
+enum DiffLine {
+    Same(usize),
+    Removed(usize),
+    Added(usize),
+}
+
+/// Line based diff, using the longest common subsequence of lines.
+fn diff_lines(expected: &[&str], actual: &[&str]) -> Vec<DiffLine> {
+    let prefix = expected
+        .iter()
+        .zip(actual.iter())
+        .take_while(|(e, a)| e == a)
+        .count();
+    let suffix = expected[prefix..]
+        .iter()
+        .rev()
+        .zip(actual[prefix..].iter().rev())
+        .take_while(|(e, a)| e == a)
+        .count();
+
+    let e_mid = &expected[prefix..expected.len() - suffix];
+    let a_mid = &actual[prefix..actual.len() - suffix];
+
+    let mut result = (0..prefix).map(DiffLine::Same).collect::<Vec<_>>();
+
+    if e_mid.len() * a_mid.len() > MAX_LCS_CELLS {
+        result.extend((0..e_mid.len()).map(|i| DiffLine::Removed(prefix + i)));
+        result.extend((0..a_mid.len()).map(|i| DiffLine::Added(prefix + i)));
+    } else {
+        // Table of longest common subsequence lengths of all suffixes.
+        let mut lcs = vec![vec![0usize; a_mid.len() + 1]; e_mid.len() + 1];
+        for e in (0..e_mid.len()).rev() {
+            for a in (0..a_mid.len()).rev() {
+                lcs[e][a] = if e_mid[e] == a_mid[a] {
+                    lcs[e + 1][a + 1] + 1
+                } else {
+                    lcs[e + 1][a].max(lcs[e][a + 1])
+                };
+            }
+        }
+
+        let (mut e, mut a) = (0, 0);
+        while e < e_mid.len() || a < a_mid.len() {
+            if e < e_mid.len() && a < a_mid.len() && e_mid[e] == a_mid[a] {
+                result.push(DiffLine::Same(prefix + e));
+                e += 1;
+                a += 1;
+            } else if a == a_mid.len()
+                || (e < e_mid.len() && lcs[e + 1][a] >= lcs[e][a + 1])
+            {
+                result.push(DiffLine::Removed(prefix + e));
+                e += 1;
+            } else {
+                result.push(DiffLine::Added(prefix + a));
+                a += 1;
+            }
+        }
+    }
+
+    let e_end = expected.len() - suffix;
+    result.extend((e_end..expected.len()).map(DiffLine::Same));
+    result
+}
+
+/// Kind of nested value opened on a pretty-printed line.
+enum Nesting {
+    /// Struct or map, with named entries.
+    Named,
+    /// Tuple or tuple struct, with positional entries.
+    Tuple(usize),
+    /// List, vector or array, with indexed entries.
+    List(usize),
+}
+
+/// Calculates the path, like `.items[2].name`, of the value printed on each
+/// line of a `{:#?}` pretty-printed value.
+fn line_paths(lines: &[&str]) -> Vec<String> {
+    let mut stack = Vec::<(String, Nesting)>::new();
+
+    lines
+        .iter()
+        .map(|line| {
+            let trimmed = line.trim();
+
+            if trimmed.starts_with(['}', ']', ')']) {
+                return stack.pop().map(|(path, _)| path).unwrap_or_default();
+            }
+
+            let path = match stack.last_mut() {
+                None => String::new(),
+                Some((parent, Nesting::Named)) => {
+                    let key = trimmed.split(": ").next().unwrap_or(trimmed);
+                    if key.starts_with('"') {
+                        format!("{parent}[{key}]")
+                    } else {
+                        format!("{parent}.{key}")
+                    }
+                }
+                Some((parent, Nesting::Tuple(index))) => {
+                    *index += 1;
+                    format!("{parent}.{}", *index - 1)
+                }
+                Some((parent, Nesting::List(index))) => {
+                    *index += 1;
+                    format!("{parent}[{}]", *index - 1)
+                }
+            };
+
+            if trimmed.ends_with('{') {
+                stack.push((path.clone(), Nesting::Named));
+            } else if trimmed.ends_with('(') {
+                stack.push((path.clone(), Nesting::Tuple(0)));
+            } else if trimmed.ends_with('[') {
+                stack.push((path.clone(), Nesting::List(0)));
+            }
+
+            path
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::explain_difference;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Order {
+        id: u32,
+        items: Vec<Item>,
+        note: (u8, &'static str),
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Item {
+        name: &'static str,
+        count: u8,
+    }
+
+    fn order(second_count: u8, note: &'static str) -> Order {
+        Order {
+            id: 7,
+            items: vec![
+                Item {
+                    name: "apple",
+                    count: 1,
+                },
+                Item {
+                    name: "pear",
+                    count: second_count,
+                },
+            ],
+            note: (1, note),
+        }
+    }
+
+    #[test]
+    fn no_explanation_for_single_line_values() {
+        assert_eq!(explain_difference(&11, &10), "");
+    }
+
+    #[test]
+    fn explains_differing_lines_and_their_paths() {
+        let expected_lines = [
+            "",
+            "",
+            "Differing paths: .items[1].count, .note.1",
+            "Diff (- expected, + actual):",
+            "  Order {",
+            "      id: 7,",
+            "      items: [",
+            "          Item {",
+            "              name: \"apple\",",
+            "              count: 1,",
+            "          },",
+            "          Item {",
+            "              name: \"pear\",",
+            "-             count: 2,",
+            "+             count: 3,",
+            "          },",
+            "      ],",
+            "      note: (",
+            "          1,",
+            "-         \"x\",",
+            "+         \"y\",",
+            "      ),",
+            "  }",
+        ];
+
+        assert_eq!(
+            explain_difference(&order(2, "x"), &order(3, "y")),
+            expected_lines.join("\n")
+        );
+    }
+
+    #[test]
+    fn explains_added_list_elements() {
+        let explanation = explain_difference(&vec![1, 2], &vec![1, 2, 3]);
+
+        assert!(
+            explanation.contains("Differing paths: [2]\n"),
+            "got {explanation}"
+        );
+        assert!(explanation.contains("\n+     3,"), "got {explanation}");
+    }
+}
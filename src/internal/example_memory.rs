@@ -0,0 +1,67 @@
+//! Memory of values associated with generated examples, for shrinkers that
+//! need more than the example itself, like how the example was generated.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// Number of examples remembered before the oldest ones start to be
+/// forgotten, to keep memory bounded for long running tests. Examples are
+/// shrunk right after being generated, so only recent examples are needed.
+const GENERATION_SIZE: usize = 10_000;
+
+/// Remembers a value per example. Examples are identified by equality, so
+/// that different examples never share a remembered value.
+///
+/// The memory is kept in two generations. When the current generation is
+/// full, it becomes the previous generation and the old previous generation
+/// is forgotten. An example recalled from the previous generation is moved to
+/// the current one.
+#[derive(Clone)]
+pub struct ExampleMemory<E, V> {
+    generations: Rc<RefCell<[HashMap<E, V>; 2]>>,
+}
+
+impl<E, V> Default for ExampleMemory<E, V> {
+    fn default() -> Self {
+        ExampleMemory {
+            generations: Rc::new(RefCell::new([
+                HashMap::new(),
+                HashMap::new(),
+            ])),
+        }
+    }
+}
+
+impl<E, V> ExampleMemory<E, V>
+where
+    E: Clone + Hash + Eq,
+    V: Clone,
+{
+    /// Remember given value for given example.
+    pub fn remember(&self, example: &E, value: V) {
+        let mut generations = self.generations.borrow_mut();
+
+        if generations[0].len() >= GENERATION_SIZE {
+            generations.swap(0, 1);
+            generations[0].clear();
+        }
+
+        generations[0].insert(example.clone(), value);
+    }
+
+    /// Recall value remembered for given example, if not forgotten.
+    pub fn recall(&self, example: &E) -> Option<V> {
+        let value = {
+            let generations = self.generations.borrow();
+            generations[0]
+                .get(example)
+                .or_else(|| generations[1].get(example))
+                .cloned()
+        }?;
+
+        self.remember(example, value.clone());
+        Some(value)
+    }
+}
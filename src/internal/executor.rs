@@ -0,0 +1,88 @@
+//! Minimal single threaded executor, for running async properties without
+//! depending on any async runtime.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Wake;
+use std::thread::Thread;
+
+/// Waker unparking the thread blocked on a future.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run given future to completion on the current thread, parking the thread
+/// while the future is pending.
+pub fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let mut future = std::pin::pin!(future);
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::Context;
+    use std::task::Poll;
+    use std::time::Duration;
+
+    /// Future being pending until woken up from another thread.
+    struct WokenLater {
+        started: bool,
+        done: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    impl Future for WokenLater {
+        type Output = u8;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u8> {
+            if self.done.load(std::sync::atomic::Ordering::SeqCst) {
+                Poll::Ready(42)
+            } else {
+                if !self.started {
+                    self.started = true;
+                    let done = self.done.clone();
+                    let waker = cx.waker().clone();
+                    std::thread::spawn(move || {
+                        std::thread::sleep(Duration::from_millis(10));
+                        done.store(true, std::sync::atomic::Ordering::SeqCst);
+                        waker.wake();
+                    });
+                }
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn runs_ready_future() {
+        assert_eq!(super::block_on(async { 1 + 2 }), 3);
+    }
+
+    #[test]
+    fn waits_for_pending_future_to_be_woken() {
+        let future = WokenLater {
+            started: false,
+            done: Default::default(),
+        };
+
+        assert_eq!(super::block_on(future), 42);
+    }
+}
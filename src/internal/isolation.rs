@@ -0,0 +1,301 @@
+//! Process isolated evaluation of examples. The test binary is started again
+//! as a child process, running only the current test, and the child is told
+//! which example to evaluate by the seed and the path to the example. The
+//! path is the index of the generated example, followed by the index of the
+//! chosen shrink candidate in each shrink step. This way, examples need not
+//! be serialized.
+//!
+//! This relies on the generator and shrinker giving the same examples and
+//! shrink candidates in the child process as in the parent process, for the
+//! same seed and path, see [crate::Conf::with_process_isolation].
+
+use crate::runner::Failure;
+use crate::Seed;
+use std::cell::RefCell;
+use std::io::Read;
+use std::io::Write;
+use std::process::Child;
+use std::process::Command;
+use std::process::ExitStatus;
+use std::process::Stdio;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Environment variable telling a child process which example to evaluate.
+const TARGET_VARIABLE: &str = "MONKEY_TEST_ISOLATED_EXAMPLE";
+
+/// Prefix of the line written by a child process, with the result of the
+/// evaluated example.
+const RESULT_MARKER: &str = "monkey-test-isolated-result:";
+
+/// Prefix of the line written by a child process, with the explanation of a
+/// failed example.
+const EXPLANATION_MARKER: &str = "monkey-test-isolated-explanation:";
+
+thread_local! {
+    /// Name of the test that has evaluated a process isolated property on the
+    /// current thread.
+    static ISOLATED_TEST: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Example to evaluate in a child process.
+pub struct Target {
+    /// Seed used for generating examples.
+    pub seed: Seed,
+    /// Path to example, see module documentation.
+    pub path: Vec<usize>,
+}
+
+/// Start the process isolated property evaluation of the current test.
+///
+/// Panics if the test already has evaluated a process isolated property,
+/// since a child process evaluates the first process isolated property of the
+/// test and can not reproduce the results of it to any later property.
+pub fn start_evaluation() {
+    let test_name = std::thread::current().name().map(str::to_string);
+
+    ISOLATED_TEST.with(|isolated_test| {
+        let mut isolated_test = isolated_test.borrow_mut();
+        if test_name.is_some() && *isolated_test == test_name {
+            panic!(
+                "Only one process isolated property is supported in each \
+                test. Please move other process isolated properties to \
+                separate tests."
+            );
+        }
+        *isolated_test = test_name;
+    })
+}
+
+/// The example to evaluate, if current process is a child process.
+pub fn child_target() -> Option<Target> {
+    let text = std::env::var(TARGET_VARIABLE).ok()?;
+    let (seed, path) = text.split_once(':')?;
+    let seed = seed.parse().ok()?;
+    let path = path
+        .split(',')
+        .map(|index| index.parse().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Target { seed, path })
+}
+
+/// Report result of evaluated example to parent process and exit the child
+/// process, without running anything more of the test.
+pub fn report_and_exit(result: Result<(), Failure>) -> ! {
+    let line = match result {
+        Ok(()) => format!("{RESULT_MARKER}ok"),
+        Err(failure) => format!(
+            "{RESULT_MARKER}{}:{}\n{EXPLANATION_MARKER}{}",
+            if failure.timed_out { "timeout" } else { "err" },
+            escape(&failure.reason),
+            escape(&failure.explanation)
+        ),
+    };
+
+    let mut stdout = std::io::stdout();
+    let _ = writeln!(stdout, "\n{line}");
+    let _ = stdout.flush();
+    std::process::exit(0)
+}
+
+/// Evaluate example in a child process, running only the current test. If a
+/// timeout is given, the child process is killed when timing out.
+pub fn evaluate_in_child_process(
+    seed: Seed,
+    path: &[usize],
+    timeout: Option<Duration>,
+) -> Result<(), Failure> {
+    let test_name = std::thread::current()
+        .name()
+        .filter(|name| *name != "main")
+        .expect(
+            "Process isolation requires the property to be evaluated in a \
+            test run by the standard test harness, where the thread is named \
+            after the test.",
+        )
+        .to_string();
+
+    let path_text = path
+        .iter()
+        .map(|index| index.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let exe = std::env::current_exe()
+        .expect("Test executable should be found for process isolation.");
+
+    let mut child = Command::new(exe)
+        .args([&test_name, "--exact", "--nocapture", "--test-threads=1"])
+        .env(TARGET_VARIABLE, format!("{seed}:{path_text}"))
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Child process should be started for process isolation.");
+
+    // Output is read while waiting, so that the child process is not blocked
+    // by full output pipes.
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let status = wait_at_most(&mut child, timeout);
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let Some(status) = status else {
+        return Err(Failure::timeout(
+            timeout.expect("only timeout can cause missing status"),
+        ));
+    };
+
+    let reported = |marker: &str| {
+        stdout.lines().find_map(|line| line.strip_prefix(marker))
+    };
+
+    match reported(RESULT_MARKER) {
+        Some("ok") => Ok(()),
+        Some(reported_result) => {
+            let (kind, reason) =
+                reported_result.split_once(':').unwrap_or(("err", ""));
+            Err(Failure {
+                reason: unescape(reason),
+                explanation: unescape(
+                    reported(EXPLANATION_MARKER).unwrap_or_default(),
+                ),
+                timed_out: kind == "timeout",
+            })
+        }
+        None => Err(crash_reason(status, &stderr).into()),
+    }
+}
+
+/// Read all of given output on a separate thread, as lossy UTF-8.
+fn read_in_background<R>(output: Option<R>) -> JoinHandle<String>
+where
+    R: Read + Send + 'static,
+{
+    std::thread::spawn(move || {
+        let mut bytes = vec![];
+        if let Some(mut output) = output {
+            // Output read before any error is still used.
+            let _ = output.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+/// Wait for child process to end, at most the given timeout. On timeout, the
+/// child process is killed and reaped, returning `None`.
+fn wait_at_most(
+    child: &mut Child,
+    timeout: Option<Duration>,
+) -> Option<ExitStatus> {
+    let wait_failed =
+        "Child process should be waited for in process isolation.";
+
+    let Some(timeout) = timeout else {
+        return Some(child.wait().expect(wait_failed));
+    };
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait().expect(wait_failed) {
+            return Some(status);
+        }
+        if Instant::now() >= deadline {
+            // Killing fails only if the child process has already ended,
+            // which is then reaped by waiting anyway.
+            let _ = child.kill();
+            let _ = child.wait();
+            return None;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+/// Failure reason of a child process ending without reporting a result.
+fn crash_reason(status: ExitStatus, stderr: &str) -> String {
+    let ending = match (status.code(), signal(status)) {
+        (Some(code), _) => format!("exited with code {code}"),
+        (None, Some(signal)) => match signal_name(signal) {
+            Some(name) => format!("was killed by signal {signal} ({name})"),
+            None => format!("was killed by signal {signal}"),
+        },
+        (None, None) => format!("ended with {status}"),
+    };
+
+    let last_output = stderr.lines().rev().find(|line| !line.trim().is_empty());
+
+    match last_output {
+        Some(line) => format!(
+            "Expecting no crash, but child process {ending}, \
+            with last output {:?}.",
+            line.trim()
+        ),
+        None => format!("Expecting no crash, but child process {ending}."),
+    }
+}
+
+#[cfg(unix)]
+fn signal(status: ExitStatus) -> Option<i32> {
+    std::os::unix::process::ExitStatusExt::signal(&status)
+}
+
+#[cfg(not(unix))]
+fn signal(_status: ExitStatus) -> Option<i32> {
+    None
+}
+
+/// Name of common signals caused by crashes.
+fn signal_name(signal: i32) -> Option<&'static str> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+
+    match signal {
+        4 => Some("SIGILL"),
+        6 => Some("SIGABRT"),
+        7 => Some("SIGBUS"),
+        8 => Some("SIGFPE"),
+        9 => Some("SIGKILL"),
+        11 => Some("SIGSEGV"),
+        _ => None,
+    }
+}
+
+/// Escape text to fit on a single line.
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+/// Reverse of [escape].
+fn unescape(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => result.push('\n'),
+                Some(other) => result.push(other),
+                None => result.push('\\'),
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn escaped_text_can_be_unescaped() {
+        let text = "Reason:\n\tline \\n with backslash\\";
+        let escaped = super::escape(text);
+
+        assert!(!escaped.contains('\n'));
+        assert_eq!(super::unescape(&escaped), text);
+    }
+}
@@ -0,0 +1,825 @@
+//! Minimal regular expression compiler, turning a regular expression into a
+//! producer of matching strings.
+//!
+//! A string is produced from a sequence of choices, one choice per
+//! alternation, character class and repetition count encountered. Any
+//! sequence of choices produces a matching string, since choices out of range
+//! are clamped and missing choices are taken as zero. Shrinking a sequence of
+//! choices toward fewer and smaller choices therefore produces simpler strings
+//! that still match the regular expression.
+
+use crate::internal::char_rank;
+use rand::Rng;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Upper limit of unbounded repetitions like `*`, `+` and `{n,}`, beyond the
+/// minimum number of repetitions, when generating strings.
+pub const MAX_UNBOUNDED_REPEAT: usize = 32;
+
+/// Characters matched by `.` and negated character classes.
+const PRINTABLE_ASCII: (char, char) = (' ', '~');
+
+/// Parsed regular expression.
+#[derive(Clone, Debug)]
+pub struct Regex {
+    root: Node,
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Literal(char),
+    Class(CharSet),
+    Concat(Vec<Node>),
+    Alternation(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+/// Set of characters, indexed from the simplest to the least simple char, see
+/// [char_rank].
+#[derive(Clone, Debug)]
+struct CharSet {
+    /// ASCII chars, in order of simplicity.
+    ascii: Vec<char>,
+    /// Sorted and non-overlapping inclusive ranges of other chars, which are
+    /// in order of simplicity by code point.
+    non_ascii: Vec<(char, char)>,
+}
+
+/// Source of choices, when producing a string.
+trait Choices {
+    /// Choose a value below given limit, or any value if no limit is given.
+    fn choose(&mut self, limit: Option<usize>) -> usize;
+}
+
+/// Random choices, limiting unbounded choices to given max. The made choices
+/// are recorded, so that they can be replayed.
+struct RandomChoices<'a, R: Rng> {
+    rng: &'a mut R,
+    unbounded_limit: usize,
+    made: Vec<u32>,
+}
+
+impl<'a, R: Rng> Choices for RandomChoices<'a, R> {
+    fn choose(&mut self, limit: Option<usize>) -> usize {
+        let limit = limit.unwrap_or(self.unbounded_limit).max(1);
+        let choice = self.rng.random_range(0..limit);
+        self.made.push(choice as u32);
+        choice
+    }
+}
+
+/// Choices replayed from a sequence of choices.
+struct ReplayedChoices<'a> {
+    choices: std::slice::Iter<'a, u32>,
+}
+
+impl<'a> Choices for ReplayedChoices<'a> {
+    fn choose(&mut self, limit: Option<usize>) -> usize {
+        let choice = self.choices.next().map(|c| *c as usize).unwrap_or(0);
+        match limit {
+            Some(limit) => choice.min(limit.saturating_sub(1)),
+            None => choice,
+        }
+    }
+}
+
+impl Regex {
+    /// Parse given regular expression.
+    pub fn parse(pattern: &str) -> Result<Regex, String> {
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            pos: 0,
+        };
+        let root = parser.alternation()?;
+
+        match parser.peek() {
+            None => Ok(Regex { root }),
+            Some(c) => {
+                Err(format!("unexpected {c:?} at position {}", parser.pos))
+            }
+        }
+    }
+
+    /// Generate a random matching string, together with the choices producing
+    /// it. Unbounded repetitions repeat at most `max_unbounded_repeat` times
+    /// beyond their minimum.
+    pub fn generate<R: Rng>(
+        &self,
+        rng: &mut R,
+        max_unbounded_repeat: usize,
+    ) -> (String, Vec<u32>) {
+        let mut choices = RandomChoices {
+            rng,
+            unbounded_limit: max_unbounded_repeat + 1,
+            made: Vec::new(),
+        };
+        let mut result = String::new();
+        produce(&self.root, &mut choices, &mut result);
+        (result, choices.made)
+    }
+
+    /// Produce the matching string of given choices.
+    pub fn replay(&self, choices: &[u32]) -> String {
+        let mut choices = ReplayedChoices {
+            choices: choices.iter(),
+        };
+        let mut result = String::new();
+        produce(&self.root, &mut choices, &mut result);
+        result
+    }
+
+    /// Find the choices producing given string, if the string matches.
+    pub fn choices_of(&self, text: &str) -> Option<Vec<u32>> {
+        let input = text.chars().collect::<Vec<_>>();
+        let mut matcher = Matcher {
+            input: &input,
+            ends: HashMap::new(),
+        };
+
+        if !matcher
+            .ends(Part::Node(&self.root), 0)
+            .contains(&input.len())
+        {
+            return None;
+        }
+
+        let mut choices = Vec::new();
+        matcher.choose(Part::Node(&self.root), 0, input.len(), &mut choices);
+        Some(choices)
+    }
+}
+
+fn produce(node: &Node, choices: &mut dyn Choices, result: &mut String) {
+    match node {
+        Node::Literal(c) => result.push(*c),
+        Node::Class(set) => {
+            result.push(set.nth(choices.choose(Some(set.len()))))
+        }
+        Node::Concat(nodes) => {
+            for node in nodes {
+                produce(node, choices, result)
+            }
+        }
+        Node::Alternation(nodes) => {
+            let index = choices.choose(Some(nodes.len()));
+            produce(&nodes[index], choices, result)
+        }
+        Node::Repeat { node, min, max } => {
+            let extra = choices.choose(max.map(|max| max - min + 1));
+            for _ in 0..(min + extra) {
+                produce(node, choices, result)
+            }
+        }
+    }
+}
+
+/// Part of a regular expression to match. Besides whole nodes, the parts
+/// are the remaining nodes of a concatenation and a given number of
+/// repetitions of a node.
+#[derive(Clone, Copy)]
+enum Part<'a> {
+    Node(&'a Node),
+    Sequence(&'a [Node]),
+    Repetitions(&'a Node, usize),
+}
+
+/// Kind, address and length or count of a part, identifying it.
+type PartKey = (usize, usize, usize);
+
+impl Part<'_> {
+    /// Identity of part, for memoization.
+    fn key(&self) -> PartKey {
+        match self {
+            Part::Node(node) => (0, *node as *const Node as usize, 0),
+            Part::Sequence(nodes) => (1, nodes.as_ptr() as usize, nodes.len()),
+            Part::Repetitions(node, count) => {
+                (2, *node as *const Node as usize, *count)
+            }
+        }
+    }
+}
+
+/// Matcher of a regular expression against an input, memoizing the end
+/// positions each part can match to from each start position. Matching is
+/// thereby polynomial in the input length, also for ambiguous regular
+/// expressions like `(a|a)*`, which would take exponential time with plain
+/// backtracking.
+struct Matcher<'a> {
+    input: &'a [char],
+    ends: HashMap<(PartKey, usize), Rc<BTreeSet<usize>>>,
+}
+
+impl<'a> Matcher<'a> {
+    /// All end positions that given part can match to from given position.
+    fn ends(&mut self, part: Part<'a>, pos: usize) -> Rc<BTreeSet<usize>> {
+        let key = (part.key(), pos);
+        if let Some(ends) = self.ends.get(&key) {
+            return ends.clone();
+        }
+
+        let ends = Rc::new(match part {
+            Part::Node(Node::Literal(c)) => {
+                self.next_if(pos, |next| next == *c)
+            }
+            Part::Node(Node::Class(set)) => {
+                self.next_if(pos, |next| set.index_of(next).is_some())
+            }
+            Part::Node(Node::Concat(nodes)) => {
+                self.ends(Part::Sequence(nodes), pos).as_ref().clone()
+            }
+            Part::Node(Node::Alternation(nodes)) => nodes
+                .iter()
+                .flat_map(|node| {
+                    self.ends(Part::Node(node), pos)
+                        .iter()
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+            Part::Node(Node::Repeat { node, min, max }) => (*min..=self
+                .max_count(*min, *max, pos))
+                .flat_map(|count| {
+                    self.ends(Part::Repetitions(node, count), pos)
+                        .iter()
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+            Part::Sequence([]) | Part::Repetitions(_, 0) => {
+                BTreeSet::from([pos])
+            }
+            Part::Sequence([first, rest @ ..]) => {
+                self.ends_after(Part::Node(first), Part::Sequence(rest), pos)
+            }
+            Part::Repetitions(node, count) => self.ends_after(
+                Part::Node(node),
+                Part::Repetitions(node, count - 1),
+                pos,
+            ),
+        });
+
+        self.ends.insert(key, ends.clone());
+        ends
+    }
+
+    /// End positions of matching given first part followed by given rest.
+    fn ends_after(
+        &mut self,
+        first: Part<'a>,
+        rest: Part<'a>,
+        pos: usize,
+    ) -> BTreeSet<usize> {
+        self.ends(first, pos)
+            .iter()
+            .flat_map(|middle| {
+                self.ends(rest, *middle).iter().copied().collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Position after given position, if the char there is accepted.
+    fn next_if(
+        &self,
+        pos: usize,
+        accept: impl Fn(char) -> bool,
+    ) -> BTreeSet<usize> {
+        match self.input.get(pos) {
+            Some(c) if accept(*c) => BTreeSet::from([pos + 1]),
+            _ => BTreeSet::new(),
+        }
+    }
+
+    /// Largest number of repetitions worth trying at given position.
+    /// Repetitions beyond the remaining input length can only match empty
+    /// strings, adding nothing.
+    fn max_count(&self, min: usize, max: Option<usize>, pos: usize) -> usize {
+        let remaining = self.input.len() - pos;
+        max.unwrap_or(usize::MAX).min(min + remaining).max(min)
+    }
+
+    /// Record the choices of matching given part from `pos` to `end`, which
+    /// must be one of its end positions. The simplest choices are preferred,
+    /// like the first alternative and the fewest repetitions.
+    fn choose(
+        &mut self,
+        part: Part<'a>,
+        pos: usize,
+        end: usize,
+        choices: &mut Vec<u32>,
+    ) {
+        match part {
+            Part::Node(Node::Literal(_)) => {}
+            Part::Node(Node::Class(set)) => {
+                let index = set.index_of(self.input[pos]);
+                choices.push(index.expect("char should be in class") as u32)
+            }
+            Part::Node(Node::Concat(nodes)) => {
+                self.choose(Part::Sequence(nodes), pos, end, choices)
+            }
+            Part::Node(Node::Alternation(nodes)) => {
+                let (index, node) = nodes
+                    .iter()
+                    .enumerate()
+                    .find(|(_, node)| {
+                        self.ends(Part::Node(node), pos).contains(&end)
+                    })
+                    .expect("some alternative should match");
+                choices.push(index as u32);
+                self.choose(Part::Node(node), pos, end, choices)
+            }
+            Part::Node(Node::Repeat { node, min, max }) => {
+                let count = (*min..=self.max_count(*min, *max, pos))
+                    .find(|count| {
+                        self.ends(Part::Repetitions(node, *count), pos)
+                            .contains(&end)
+                    })
+                    .expect("some number of repetitions should match");
+                choices.push((count - min) as u32);
+                self.choose(Part::Repetitions(node, count), pos, end, choices)
+            }
+            Part::Sequence([]) | Part::Repetitions(_, 0) => {}
+            Part::Sequence([first, rest @ ..]) => self.choose_after(
+                Part::Node(first),
+                Part::Sequence(rest),
+                pos,
+                end,
+                choices,
+            ),
+            Part::Repetitions(node, count) => self.choose_after(
+                Part::Node(node),
+                Part::Repetitions(node, count - 1),
+                pos,
+                end,
+                choices,
+            ),
+        }
+    }
+
+    /// Record the choices of matching given first part followed by given
+    /// rest, from `pos` to `end`.
+    fn choose_after(
+        &mut self,
+        first: Part<'a>,
+        rest: Part<'a>,
+        pos: usize,
+        end: usize,
+        choices: &mut Vec<u32>,
+    ) {
+        let middle = *self
+            .ends(first, pos)
+            .iter()
+            .find(|middle| self.ends(rest, **middle).contains(&end))
+            .expect("some split should match");
+        self.choose(first, pos, middle, choices);
+        self.choose(rest, middle, end, choices)
+    }
+}
+
+impl CharSet {
+    fn new(ranges: Vec<(char, char)>) -> CharSet {
+        let mut ranges = ranges;
+        ranges.sort();
+
+        let mut merged: Vec<(char, char)> = Vec::new();
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start as u32 <= last.1 as u32 + 1 => {
+                    last.1 = last.1.max(end)
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+
+        let contains = |c: &char| {
+            merged
+                .iter()
+                .any(|(start, end)| (*start..=*end).contains(c))
+        };
+
+        let mut ascii = ('\0'..='\x7f').filter(contains).collect::<Vec<_>>();
+        ascii.sort_by_key(|c| char_rank::rank(*c));
+
+        let non_ascii = merged
+            .iter()
+            .filter(|(_, end)| !end.is_ascii())
+            .map(|(start, end)| (*start.max(&'\u{80}'), *end))
+            .collect();
+
+        CharSet { ascii, non_ascii }
+    }
+
+    /// Characters of printable ASCII not in this set.
+    fn negated(&self) -> CharSet {
+        let (first, last) = PRINTABLE_ASCII;
+        CharSet::new(
+            (first..=last)
+                .filter(|c| self.index_of(*c).is_none())
+                .map(|c| (c, c))
+                .collect(),
+        )
+    }
+
+    fn ranges(&self) -> Vec<(char, char)> {
+        self.ascii
+            .iter()
+            .map(|c| (*c, *c))
+            .chain(self.non_ascii.iter().cloned())
+            .collect()
+    }
+
+    fn len(&self) -> usize {
+        self.ascii.len()
+            + self
+                .non_ascii
+                .iter()
+                .map(|(start, end)| range_len(*start, *end))
+                .sum::<usize>()
+    }
+
+    fn nth(&self, index: usize) -> char {
+        if let Some(c) = self.ascii.get(index) {
+            return *c;
+        }
+
+        let mut index = index - self.ascii.len();
+        for (start, end) in &self.non_ascii {
+            let len = range_len(*start, *end);
+            if index < len {
+                return (*start..=*end).nth(index).expect("index in range");
+            }
+            index -= len;
+        }
+        unreachable!("index {index} out of character set {self:?}")
+    }
+
+    fn index_of(&self, c: char) -> Option<usize> {
+        if c.is_ascii() {
+            return self.ascii.iter().position(|member| *member == c);
+        }
+
+        let mut offset = self.ascii.len();
+        for (start, end) in &self.non_ascii {
+            if (*start..=*end).contains(&c) {
+                return Some(offset + range_len(*start, c) - 1);
+            }
+            offset += range_len(*start, *end);
+        }
+        None
+    }
+}
+
+/// Number of chars in inclusive range, not counting surrogates.
+fn range_len(start: char, end: char) -> usize {
+    (start..=end).count()
+}
+
+fn digits() -> Vec<(char, char)> {
+    vec![('0', '9')]
+}
+
+fn words() -> Vec<(char, char)> {
+    vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')]
+}
+
+fn spaces() -> Vec<(char, char)> {
+    vec![(' ', ' '), ('\t', '\t'), ('\n', '\n'), ('\r', '\r')]
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).cloned()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn alternation(&mut self) -> Result<Node, String> {
+        let mut branches = vec![self.concat()?];
+        while self.eat('|') {
+            branches.push(self.concat()?);
+        }
+
+        Ok(if branches.len() == 1 {
+            branches.remove(0)
+        } else {
+            Node::Alternation(branches)
+        })
+    }
+
+    fn concat(&mut self) -> Result<Node, String> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '|' | ')' => break,
+                // Anchors are implied, since whole strings are generated.
+                '^' | '$' => self.pos += 1,
+                _ => {
+                    let atom = self.atom()?;
+                    nodes.push(self.quantified(atom)?);
+                }
+            }
+        }
+
+        Ok(if nodes.len() == 1 {
+            nodes.remove(0)
+        } else {
+            Node::Concat(nodes)
+        })
+    }
+
+    fn quantified(&mut self, atom: Node) -> Result<Node, String> {
+        let mut node = atom;
+        loop {
+            let (min, max) = match self.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') => {
+                    self.pos += 1;
+                    self.counted_repetition()?
+                }
+                _ => return Ok(node),
+            };
+            // Skip the quantifier char, or the closing brace.
+            self.pos += 1;
+            // Lazy quantifiers generate the same strings as greedy ones.
+            self.eat('?');
+
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+            };
+        }
+    }
+
+    /// Parse `n}`, `n,}` or `n,m}`, after the opening brace.
+    fn counted_repetition(&mut self) -> Result<(usize, Option<usize>), String> {
+        let min = self.number()?;
+        let max = if self.eat(',') {
+            if self.peek() == Some('}') {
+                None
+            } else {
+                Some(self.number()?)
+            }
+        } else {
+            Some(min)
+        };
+
+        if self.peek() != Some('}') {
+            return Err(format!("expected '}}' at position {}", self.pos));
+        }
+        if max.is_some_and(|max| max < min) {
+            return Err(format!(
+                "repetition maximum is less than minimum at position {}",
+                self.pos
+            ));
+        }
+        Ok((min, max))
+    }
+
+    fn number(&mut self) -> Result<usize, String> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .map_err(|_| format!("expected number at position {start}"))
+    }
+
+    fn atom(&mut self) -> Result<Node, String> {
+        let pos = self.pos;
+        match self.next() {
+            Some('(') => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err(format!(
+                        "unsupported group kind at position {pos}"
+                    ));
+                }
+                let node = self.alternation()?;
+                if !self.eat(')') {
+                    return Err(format!("unclosed group at position {pos}"));
+                }
+                Ok(node)
+            }
+            Some('[') => self.class(),
+            Some('.') => Ok(Node::Class(CharSet::new(vec![PRINTABLE_ASCII]))),
+            Some('\\') => Ok(match self.escape()? {
+                Escaped::Char(c) => Node::Literal(c),
+                Escaped::Class(set) => Node::Class(set),
+            }),
+            Some(c @ ('*' | '+' | '?' | '{')) => {
+                Err(format!("nothing to repeat with {c:?} at position {pos}"))
+            }
+            Some(c) => Ok(Node::Literal(c)),
+            None => Err(format!("unexpected end at position {pos}")),
+        }
+    }
+
+    fn class(&mut self) -> Result<Node, String> {
+        let start = self.pos - 1;
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+
+        loop {
+            let c = match self.next() {
+                None => {
+                    return Err(format!(
+                        "unclosed character class at position {start}"
+                    ))
+                }
+                Some(']') if !first => break,
+                Some(c) => c,
+            };
+            first = false;
+
+            let low = match c {
+                '\\' => match self.escape()? {
+                    Escaped::Char(c) => c,
+                    Escaped::Class(set) => {
+                        ranges.extend(set.ranges());
+                        continue;
+                    }
+                },
+                c => c,
+            };
+
+            let is_range = self.peek() == Some('-')
+                && self.chars.get(self.pos + 1).is_some_and(|c| *c != ']');
+
+            if is_range {
+                self.pos += 1;
+                let high = match self.next() {
+                    Some('\\') => match self.escape()? {
+                        Escaped::Char(c) => c,
+                        Escaped::Class(_) => {
+                            return Err(format!(
+                                "invalid class range at position {}",
+                                self.pos
+                            ))
+                        }
+                    },
+                    Some(c) => c,
+                    None => unreachable!("peeked char"),
+                };
+                if high < low {
+                    return Err(format!(
+                        "invalid class range {low:?}-{high:?} at position {}",
+                        self.pos
+                    ));
+                }
+                ranges.push((low, high));
+            } else {
+                ranges.push((low, low));
+            }
+        }
+
+        let set = CharSet::new(ranges);
+        let set = if negated { set.negated() } else { set };
+
+        if set.len() == 0 {
+            return Err(format!("empty character class at position {start}"));
+        }
+        Ok(Node::Class(set))
+    }
+
+    fn escape(&mut self) -> Result<Escaped, String> {
+        let pos = self.pos;
+        Ok(match self.next() {
+            Some('d') => Escaped::Class(CharSet::new(digits())),
+            Some('w') => Escaped::Class(CharSet::new(words())),
+            Some('s') => Escaped::Class(CharSet::new(spaces())),
+            Some('D') => Escaped::Class(CharSet::new(digits()).negated()),
+            Some('W') => Escaped::Class(CharSet::new(words()).negated()),
+            Some('S') => Escaped::Class(CharSet::new(spaces()).negated()),
+            Some('n') => Escaped::Char('\n'),
+            Some('t') => Escaped::Char('\t'),
+            Some('r') => Escaped::Char('\r'),
+            Some(c) if c.is_ascii_alphanumeric() => {
+                return Err(format!(
+                    "unsupported escape \\{c} at position {pos}"
+                ))
+            }
+            Some(c) => Escaped::Char(c),
+            None => return Err(format!("unexpected end at position {pos}")),
+        })
+    }
+}
+
+enum Escaped {
+    Char(char),
+    Class(CharSet),
+}
+
+#[cfg(test)]
+mod test {
+    use super::Regex;
+    use rand::SeedableRng;
+
+    fn assert_round_trip(pattern: &str, text: &str, expected: &[u32]) {
+        let regex = Regex::parse(pattern).unwrap();
+        let choices = regex.choices_of(text);
+
+        assert_eq!(choices.as_deref(), Some(expected));
+        assert_eq!(regex.replay(expected), text);
+    }
+
+    #[test]
+    fn finds_choices_of_matching_string() {
+        assert_round_trip("[a-c]{2,3}", "cab", &[1, 2, 0, 1]);
+        assert_round_trip("x|y|z", "y", &[1]);
+        assert_round_trip("(ab)*c", "ababc", &[2]);
+        assert_round_trip("a+a", "aaa", &[1]);
+        assert_round_trip(r"\d\.\w", "7.Q", &[7, 42]);
+        assert_round_trip("^[^a-z]$", "!", &[37]);
+    }
+
+    #[test]
+    fn no_choices_of_non_matching_string() {
+        let regex = Regex::parse("[a-z]{3}@x").unwrap();
+
+        assert_eq!(regex.choices_of("ab@x"), None);
+        assert_eq!(regex.choices_of("abc@xy"), None);
+    }
+
+    #[test]
+    fn matches_ambiguous_patterns_in_polynomial_time() {
+        let start = std::time::Instant::now();
+        let choices_of = |pattern, text: &str| {
+            Regex::parse(pattern).unwrap().choices_of(text)
+        };
+
+        let a_40 = "a".repeat(40);
+        assert_eq!(
+            choices_of("(a|a)*", &a_40),
+            Some(vec![40; 1].into_iter().chain([0; 40]).collect())
+        );
+        assert_eq!(choices_of("(a|a)*", &(a_40 + "b")), None);
+        assert_eq!(choices_of(r"(\d+ ?)*", &("1".repeat(40) + "!")), None);
+
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn replays_any_choices_to_a_match() {
+        let regex = Regex::parse("[a-z]{3,8}@[a-z]+\\.com").unwrap();
+
+        assert_eq!(regex.replay(&[]), "aaa@a.com");
+        assert_eq!(regex.replay(&[99, 25, 25]), "zzaaaaaa@a.com");
+    }
+
+    #[test]
+    fn generates_matching_strings() {
+        let regex = Regex::parse("(foo|ba[rz])-\\d{2,}_?").unwrap();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1337);
+
+        for _ in 0..1000 {
+            let (text, choices) = regex.generate(&mut rng, 10);
+            assert_eq!(regex.replay(&choices), text);
+            assert!(regex.choices_of(&text).is_some(), "{text:?} should match");
+        }
+    }
+
+    #[test]
+    fn reports_invalid_patterns() {
+        let error = |pattern| Regex::parse(pattern).unwrap_err();
+
+        assert_eq!(error("(ab"), "unclosed group at position 0");
+        assert_eq!(error("[ab"), "unclosed character class at position 0");
+        assert_eq!(
+            error("a{3,2}"),
+            "repetition maximum is less than minimum at position 5"
+        );
+        assert_eq!(error("*a"), "nothing to repeat with '*' at position 0");
+        assert_eq!(error("a)"), "unexpected ')' at position 1");
+        assert_eq!(error("[z-a]"), "invalid class range 'z'-'a' at position 4");
+        assert_eq!(error(r"\p"), "unsupported escape \\p at position 1");
+    }
+}
@@ -1,20 +1,28 @@
+use rand::Rng;
+
 /// A ratio of an outcome in relation to the aggregated sum of all ratios.
-pub type Ratio = u8;
+pub type Ratio = u32;
 
-/// Maps from a sample domain to a target type range.
+/// Picks targets at random, according to a weight per target.
 ///
 /// Let say we want to toss a coin with wanted distribution 75% heads
-/// and 25% tails. Then we can specify the sample domain as \[1, 4\], where
-/// first three samples (1, 2 and 3) returns a head and one sample (4)
-/// returns a tail.
+/// and 25% tails. Then we can specify the weights 3 and 1:
 /// ```text
-/// SampleTarget::new_from_ratios(&[(3, "head"),((1, "tail"))]);
+/// SampleTarget::with_ratios(&[(3, "head"), (1, "tail")]);
 /// ```
+///
+/// Picking is done in constant time, regardless of the number of targets, by
+/// the alias method. Each target has a column, which is picked uniformly. A
+/// column keeps its own target with some probability, or else hands over to an
+/// alias target, which fills up the rest of the column.
 #[derive(Clone)]
 pub struct SampleTarget<E> {
-    /// Sample max per example, in ascending order, so pervious max defines
-    /// lower bound for this example.
-    sample_max_per_target: Vec<(usize, E)>,
+    /// Targets with non-zero weight, in the order they were given.
+    targets: Vec<E>,
+    /// Probability per column of keeping the target of the column.
+    keep_probability: Vec<f64>,
+    /// Alias target per column, used when not keeping the column target.
+    alias: Vec<usize>,
 }
 
 impl<T> SampleTarget<T> {
@@ -36,64 +44,191 @@ impl<T> SampleTarget<T> {
     where
         T: Clone + core::fmt::Debug,
     {
-        let nonzero_ratios_and_targets = ratios_and_targets
-            .iter()
-            .filter(|(ratio, _)| *ratio > 0u8)
-            .collect::<Vec<_>>();
-
-        if nonzero_ratios_and_targets.is_empty() {
+        if ratios_and_targets.iter().all(|(ratio, _)| *ratio == 0) {
             panic!(
                 "Given argument {ratios_and_targets:?} has no target value \
              with non-zero ratio."
             );
         }
 
-        let mut sample_max_per_target = Vec::<(usize, T)>::new();
-        let mut max = 0usize;
-        for (ratio, target) in nonzero_ratios_and_targets {
-            max += *ratio as usize;
-            sample_max_per_target.push((max, target.clone()));
+        SampleTarget::with_weights(
+            ratios_and_targets
+                .iter()
+                .map(|(ratio, target)| (*ratio as f64, target.clone())),
+        )
+    }
+
+    /// Create new sample target from floating point weights.
+    pub fn with_weights(
+        weights_and_targets: impl IntoIterator<Item = (f64, T)>,
+    ) -> SampleTarget<T>
+    where
+        T: core::fmt::Debug,
+    {
+        let weights_and_targets =
+            weights_and_targets.into_iter().collect::<Vec<_>>();
+
+        if let Some((weight, target)) = weights_and_targets
+            .iter()
+            .find(|(weight, _)| !(weight.is_finite() && *weight >= 0.0))
+        {
+            panic!(
+                "Given weight {weight:?} of target {target:?} is not a \
+                finite and non-negative number."
+            );
+        }
+
+        if weights_and_targets.iter().all(|(weight, _)| *weight == 0.0) {
+            panic!(
+                "Given argument {weights_and_targets:?} has no target value \
+             with non-zero ratio."
+            );
         }
 
+        let (weights, targets): (Vec<f64>, Vec<T>) = weights_and_targets
+            .into_iter()
+            .filter(|(weight, _)| *weight > 0.0)
+            .unzip();
+
+        let (keep_probability, alias) = alias_table(&weights);
+
         SampleTarget {
-            sample_max_per_target,
+            targets,
+            keep_probability,
+            alias,
         }
     }
 
     /// Map values to other type, keeping the same ratios.
     pub fn map<Q>(self, f: impl Fn(T) -> Q) -> SampleTarget<Q> {
         SampleTarget {
-            sample_max_per_target: self
-                .sample_max_per_target
-                .into_iter()
-                .map(|pair| (pair.0, f(pair.1)))
-                .collect::<Vec<_>>(),
+            targets: self.targets.into_iter().map(f).collect::<Vec<_>>(),
+            keep_probability: self.keep_probability,
+            alias: self.alias,
+        }
+    }
+
+    /// All targets, in the order they were given.
+    pub fn targets(&self) -> impl Iterator<Item = &T> {
+        self.targets.iter()
+    }
+
+    /// Pick a random target.
+    pub fn pick<R: Rng>(&self, rng: &mut R) -> &T {
+        &self.targets[self.pick_index(rng)]
+    }
+
+    /// Pick a random target.
+    pub fn pick_mut<R: Rng>(&mut self, rng: &mut R) -> &mut T {
+        let index = self.pick_index(rng);
+        &mut self.targets[index]
+    }
+
+    fn pick_index<R: Rng>(&self, rng: &mut R) -> usize {
+        let column = rng.random_range(0..self.targets.len());
+
+        if rng.random::<f64>() < self.keep_probability[column] {
+            column
+        } else {
+            self.alias[column]
         }
     }
+}
+
+/// Build alias table, by Vose's alias method, from positive weights. Returns
+/// the probability of keeping each column and the alias of each column.
+fn alias_table(weights: &[f64]) -> (Vec<f64>, Vec<usize>) {
+    let count = weights.len();
+    let sum: f64 = weights.iter().sum();
+
+    // Weights scaled so that the mean weight is 1.
+    let mut scaled = weights
+        .iter()
+        .map(|weight| weight * count as f64 / sum)
+        .collect::<Vec<_>>();
 
-    /// Sample domain min is always 1.
-    pub fn sample_domain_max(&self) -> usize {
-        self.sample_max_per_target
-            .last()
-            .expect("at least one target")
-            .0
+    let mut keep_probability = vec![1.0; count];
+    let mut alias = (0..count).collect::<Vec<_>>();
+
+    let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+        (0..count).partition(|index| scaled[*index] < 1.0);
+
+    while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+        small.pop();
+        large.pop();
+
+        keep_probability[less] = scaled[less];
+        alias[less] = more;
+        scaled[more] = scaled[more] + scaled[less] - 1.0;
+
+        if scaled[more] < 1.0 {
+            small.push(more);
+        } else {
+            large.push(more);
+        }
     }
 
-    /// Translate from sample domain to target type value.
-    pub fn target_from_sample(&self, sample: usize) -> Option<&T> {
-        self.sample_max_per_target
+    // Remaining columns, only left due to rounding errors, keep their own
+    // target with the initial probability 1.
+    (keep_probability, alias)
+}
+
+#[cfg(test)]
+mod test {
+    use super::SampleTarget;
+    use rand::SeedableRng;
+
+    fn frequencies(target: &SampleTarget<usize>, picks: usize) -> Vec<f64> {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1337);
+        let mut counts = vec![0usize; target.targets().count()];
+
+        for _ in 0..picks {
+            counts[*target.pick(&mut rng)] += 1;
+        }
+
+        counts
             .iter()
-            .filter(|(max, _)| sample <= *max)
-            .map(|(_, target)| target)
-            .next()
+            .map(|count| *count as f64 / picks as f64)
+            .collect()
+    }
+
+    #[test]
+    fn picks_according_to_skewed_weights() {
+        let target =
+            SampleTarget::with_weights(vec![(1.0, 0), (1000.0, 1), (9.0, 2)]);
+
+        let actual = frequencies(&target, 1_000_000);
+
+        assert!((actual[0] - 0.001).abs() < 0.0005, "{actual:?}");
+        assert!((actual[1] - 0.990).abs() < 0.002, "{actual:?}");
+        assert!((actual[2] - 0.009).abs() < 0.001, "{actual:?}");
+    }
+
+    #[test]
+    fn picks_evenly_from_thousands_of_targets() {
+        let target = SampleTarget::evenly(&(0..5000).collect::<Vec<_>>());
+
+        let actual = frequencies(&target, 1_000_000);
+
+        assert!(
+            actual.iter().all(|freq| (freq - 0.0002).abs() < 0.0001),
+            "all targets should have the same frequency"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Given weight NaN of target 'x' is not a finite and \
+        non-negative number."
+    )]
+    fn panics_on_nan_weight() {
+        SampleTarget::with_weights(vec![(1.0, 'y'), (f64::NAN, 'x')]);
     }
 
-    /// Translate from sample domain to target type value.
-    pub fn target_from_sample_mut(&mut self, sample: usize) -> Option<&mut T> {
-        self.sample_max_per_target
-            .iter_mut()
-            .filter(|(max, _)| sample <= *max)
-            .map(|(_, target)| target)
-            .next()
+    #[test]
+    #[should_panic(expected = "Given weight -1.0 of target 'x' is not a \
+        finite and non-negative number.")]
+    fn panics_on_negative_weight() {
+        SampleTarget::with_weights(vec![(1.0, 'y'), (-1.0, 'x')]);
     }
 }
@@ -0,0 +1,216 @@
+//! Built-in tables of unicode edge cases and canonically equivalent strings,
+//! used by the unicode aware string generators.
+//!
+//! Strings are built from pieces, where each piece is an index of a category
+//! of a table and an index of an item in that category. Items are ordered
+//! from simpler to more exotic, since pieces shrink toward index zero.
+
+use crate::internal::char_rank;
+use crate::internal::sample_target::Ratio;
+
+/// Items of a category in a table.
+pub enum Items {
+    /// Printable ASCII characters, in the order they shrink.
+    Ascii,
+    /// Every code point in the inclusive range.
+    Range(u32, u32),
+    /// Given strings.
+    List(&'static [&'static str]),
+}
+
+impl Items {
+    /// Number of items in category.
+    pub fn len(&self) -> usize {
+        match self {
+            Items::Ascii => 95,
+            Items::Range(first, last) => (last - first + 1) as usize,
+            Items::List(items) => items.len(),
+        }
+    }
+
+    /// Item at given index. Indices beyond the last item give the last item.
+    pub fn get(&self, index: usize) -> String {
+        let index = index.min(self.len() - 1);
+        match self {
+            Items::Ascii => char_rank::from_rank(index as u32)
+                .expect("printable ASCII has valid ranks")
+                .to_string(),
+            Items::Range(first, _) => char::from_u32(first + index as u32)
+                .expect("ranges of tables only have valid code points")
+                .to_string(),
+            Items::List(items) => items[index].to_string(),
+        }
+    }
+}
+
+/// Categories of text that often break text processing, together with plain
+/// ASCII, and the ratio each category is used with.
+pub const EDGE_CASES: &[(Ratio, Items)] = &[
+    (30, Items::Ascii),
+    // Combining diacritical marks, joining whatever precedes them, if
+    // anything.
+    (10, Items::Range(0x300, 0x36F)),
+    // Letters with tricky case mapping or normalization.
+    (
+        10,
+        Items::List(&[
+            "\u{E9}",
+            "e\u{301}",
+            "\u{DF}",
+            "\u{1E9E}",
+            "\u{130}",
+            "\u{131}",
+            "\u{3A3}",
+            "\u{3C2}",
+            "\u{1C5}",
+            "\u{FB01}",
+            "\u{212B}",
+            "\u{2126}",
+            "\u{212A}",
+            "\u{4E2D}",
+            "\u{D55C}",
+            "\u{1112}\u{1161}\u{11AB}",
+        ]),
+    ),
+    // Emoji, with modifiers, flags, keycaps and ZWJ sequences.
+    (
+        10,
+        Items::List(&[
+            "\u{1F600}",
+            "\u{2764}\u{FE0F}",
+            "\u{1F44D}\u{1F3FD}",
+            "\u{1F1F8}\u{1F1EA}",
+            "1\u{FE0F}\u{20E3}",
+            "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}",
+            "\u{1F9D1}\u{1F3FF}\u{200D}\u{1F680}",
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}",
+        ]),
+    ),
+    // Right-to-left text and bidirectional control characters.
+    (
+        10,
+        Items::List(&[
+            "\u{5E9}\u{5DC}\u{5D5}\u{5DD}",
+            "\u{645}\u{631}\u{62D}\u{628}\u{627}",
+            "\u{200E}",
+            "\u{200F}",
+            "\u{61C}",
+            "\u{202A}",
+            "\u{202B}",
+            "\u{202C}",
+            "\u{202D}",
+            "\u{202E}",
+            "\u{2066}",
+            "\u{2067}",
+            "\u{2068}",
+            "\u{2069}",
+        ]),
+    ),
+    // Invisible characters, like byte order marks and zero width characters.
+    (
+        10,
+        Items::List(&[
+            "\u{FEFF}", "\u{A0}", "\u{AD}", "\u{200B}", "\u{200C}", "\u{200D}",
+            "\u{2060}", "\u{FE0F}", "\u{180E}", "\u{3000}",
+        ]),
+    ),
+    // Code points at boundaries, like the ones adjacent to the surrogate
+    // range, noncharacters and line separators.
+    (
+        10,
+        Items::List(&[
+            "\u{0}",
+            "\u{7F}",
+            "\u{80}",
+            "\u{85}",
+            "\u{2028}",
+            "\u{2029}",
+            "\u{D7FF}",
+            "\u{E000}",
+            "\u{FFFD}",
+            "\u{FFFE}",
+            "\u{FFFF}",
+            "\u{10000}",
+            "\u{10FFFF}",
+        ]),
+    ),
+];
+
+/// Canonically equivalent pairs of strings, in normalization form NFC and
+/// NFD respectively.
+///
+/// Each NFD string starts with a character that does not compose with what
+/// precedes it, so concatenated pairs are still in NFC and NFD.
+pub const NFC_AND_NFD: &[(&str, &str)] = &[
+    ("\u{E9}", "e\u{301}"),
+    ("\u{E8}", "e\u{300}"),
+    ("\u{F1}", "n\u{303}"),
+    ("\u{F6}", "o\u{308}"),
+    ("\u{FC}", "u\u{308}"),
+    ("\u{E7}", "c\u{327}"),
+    ("\u{C5}", "A\u{30A}"),
+    ("\u{1EA1}", "a\u{323}"),
+    ("\u{1D6}", "u\u{308}\u{304}"),
+    ("\u{1FB}", "a\u{30A}\u{301}"),
+    ("\u{1EC7}", "e\u{323}\u{302}"),
+    ("\u{1EF1}", "u\u{31B}\u{323}"),
+    ("\u{1E0D}\u{307}", "d\u{323}\u{307}"),
+    ("\u{3CE}", "\u{3C9}\u{301}"),
+    ("\u{439}", "\u{438}\u{306}"),
+    ("\u{AC00}", "\u{1100}\u{1161}"),
+    ("\u{D55C}", "\u{1112}\u{1161}\u{11AB}"),
+    ("\u{D7A3}", "\u{1112}\u{1175}\u{11C2}"),
+];
+
+#[cfg(test)]
+mod test {
+    use super::Items;
+    use super::EDGE_CASES;
+    use super::NFC_AND_NFD;
+
+    #[test]
+    fn items_are_clamped_to_last_item() {
+        assert_eq!(Items::Ascii.get(0), "a");
+        assert_eq!(Items::Ascii.get(1000), "~");
+        assert_eq!(Items::Range(0x300, 0x36F).get(1000), "\u{36F}");
+        assert_eq!(Items::List(&["x", "y"]).get(2), "y");
+    }
+
+    #[test]
+    fn all_items_of_edge_cases_are_non_empty() {
+        for (_, items) in EDGE_CASES {
+            for index in 0..items.len() {
+                assert!(!items.get(index).is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn nfd_strings_are_decomposed_into_more_characters() {
+        for (nfc, nfd) in NFC_AND_NFD {
+            assert!(
+                nfc.chars().count() < nfd.chars().count(),
+                "{nfc:?} vs {nfd:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn hangul_syllables_are_decomposed_algorithmically() {
+        for (nfc, nfd) in NFC_AND_NFD {
+            let c = nfc.chars().next().unwrap() as u32;
+            if !(0xAC00..=0xD7A3).contains(&c) {
+                continue;
+            }
+            let index = c - 0xAC00;
+            let mut expected =
+                vec![0x1100 + index / 588, 0x1161 + index % 588 / 28];
+            if index % 28 != 0 {
+                expected.push(0x11A7 + index % 28);
+            }
+
+            let actual = nfd.chars().map(|c| c as u32).collect::<Vec<_>>();
+            assert_eq!(actual, expected, "{nfc:?}");
+        }
+    }
+}
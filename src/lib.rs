@@ -12,8 +12,10 @@
 
 mod config;
 mod convenience_traits;
+pub mod formatters;
 pub mod gens;
 mod internal;
+pub mod reporters;
 mod runner;
 pub mod shrinks;
 
@@ -50,6 +52,19 @@ pub type BoxShrink<E> = Box<dyn Shrink<E>>;
 /// A boxed generator of example type `E`
 pub type BoxGen<E> = Box<dyn Gen<E>>;
 
+/// A boxed reporter
+pub type BoxReporter = Box<dyn Reporter>;
+
+/// A shared formatter, presenting examples of type `E` as text.
+pub type ExampleFormatter<E> = std::rc::Rc<dyn Fn(&E) -> String>;
+
+/// A boxed future with output type `O`.
+pub type BoxFuture<O> = std::pin::Pin<Box<dyn std::future::Future<Output = O>>>;
+
+/// A function running a future to completion on the current thread. See
+/// [Conf::with_block_on].
+pub type BlockOn = fn(BoxFuture<()>);
+
 /// A property is something that should hold, for all given examples.
 pub type Property<E> = fn(E) -> bool;
 
@@ -107,6 +122,58 @@ where
     fn candidates(&self, original: E) -> BoxIter<E>;
 }
 
+/// The reporter trait, for receiving progress and failure information while a
+/// property is tested. See module [reporters] for built in reporters.
+///
+/// All functions have default implementations, so an implementation only
+/// needs to override the events of interest. Examples are handed over as
+/// `&dyn Debug`, since a reporter is configured before the example type is
+/// known. Reporters are `Send` and `Sync`, so that a configuration can be
+/// shared between threads.
+pub trait Reporter: CloneReporter + Send + Sync {
+    /// Called once before the first example is tested.
+    fn run_started(
+        &self,
+        _title: Option<&str>,
+        _seed: Seed,
+        _example_count: u32,
+    ) {
+    }
+
+    /// Called for each example that the property holds for. The `index` is
+    /// zero based.
+    fn example_passed(&self, _index: u64) {}
+
+    /// Called when the first failing example is found, before shrinking.
+    fn failure_found(&self, _example: &dyn std::fmt::Debug, _reason: &str) {}
+
+    /// Called for each shrunken candidate that still fails the property. The
+    /// `step` count starts at one.
+    fn shrink_step(
+        &self,
+        _step: u64,
+        _example: &dyn std::fmt::Debug,
+        _reason: &str,
+    ) {
+    }
+
+    /// Called once when the property test run is done, either succeeding or
+    /// failing.
+    fn run_finished(
+        &self,
+        _success_count: u64,
+        _shrink_count: u64,
+        _failed: bool,
+    ) {
+    }
+
+    /// Called when an assert fails, returning the message the assert panics
+    /// with. Defaults to [reporters::failure_message].
+    fn assert_failed(&self, failure: &reporters::FailureReport) -> String {
+        reporters::failure_message(failure)
+    }
+}
+
 // Doctest the readme file
 #[doc = include_str!("../README.md")]
 #[cfg(doctest)]
@@ -0,0 +1,375 @@
+//! The `reporters` module contains built in reporters, receiving progress and
+//! failure information while a property is tested.
+//!
+//! A reporter is selected with [crate::Conf::with_reporter]. If no reporter is
+//! selected, the [quiet] reporter is used, only relying on the panic message
+//! of a failed assert for presenting the result.
+//!
+//! ```rust
+//! use monkey_test::*;
+//!
+//! monkey_test()
+//!     .with_reporter(reporters::verbose())
+//!     .with_generator(gens::u8::any())
+//!     .test_true(|x| x < 100)
+//!     .assert_minimum_failure(100);
+//! ```
+
+use crate::BoxReporter;
+use crate::Reporter;
+use crate::Seed;
+use std::fmt::Debug;
+use std::fmt::Write;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+
+/// Reporter not reporting anything. This is the default reporter.
+pub fn quiet() -> BoxReporter {
+    Box::new(QuietReporter {})
+}
+
+/// Reporter writing all events to standard error, including every shrink step
+/// taken when shrinking a failing example.
+pub fn verbose() -> BoxReporter {
+    Box::new(VerboseReporter {})
+}
+
+/// Reporter writing a short progress line to standard error for every tenth
+/// part of the examples tested and for every 100 shrink steps taken. Useful
+/// for properties with high example count or slow code under test.
+pub fn progress() -> BoxReporter {
+    Box::new(ProgressReporter {
+        example_count: AtomicU32::new(0),
+    })
+}
+
+/// A failed assert, given to [Reporter::assert_failed]. Examples are already
+/// formatted, see [crate::ConfAndGen::with_formatter].
+pub struct FailureReport<'a> {
+    /// Optional title of the failed property.
+    pub title: Option<&'a str>,
+    /// The minimum failure found.
+    pub minimum_failure: &'a str,
+    /// Reason for the minimum failure.
+    pub reason: &'a str,
+    /// See [crate::MonkeyResult#variant.MonkeyErr.field.flaky].
+    pub flaky: bool,
+    /// The seed used for generating the examples.
+    pub seed: Seed,
+    /// Successful example count tried before finding a failure.
+    pub success_count: u64,
+    /// The original failure, followed by some of the other failures found
+    /// while shrinking.
+    pub other_failures: &'a [String],
+}
+
+/// Message presenting a failed assert, used by all built in reporters.
+pub fn failure_message(failure: &FailureReport) -> String {
+    let first_line = match failure.title {
+        Some(t) => format!("Monkey test property \"{t}\" failed!"),
+        None => "Monkey test property failed!".into(),
+    };
+
+    let other_failures_text: String = failure.other_failures.iter().fold(
+        String::new(),
+        |mut output, failure| {
+            let _ = write!(output, "\n\t{failure}");
+            output
+        },
+    );
+
+    let flaky_text = if failure.flaky {
+        "\nFlaky: The property sometimes passed when re-running the \
+        minimum failure."
+    } else {
+        ""
+    };
+
+    let FailureReport {
+        minimum_failure,
+        reason,
+        seed,
+        success_count,
+        ..
+    } = failure;
+
+    format!(
+        "{first_line}\n\
+        Failure: {minimum_failure}\n\
+        Reason: {reason}{flaky_text}\n\
+        \n\
+        Reproduction seed: {seed}\n\
+        Success count before failure: {success_count}\n\
+        Other failures:{other_failures_text}\n",
+    )
+}
+
+#[derive(Clone)]
+struct QuietReporter {}
+
+impl Reporter for QuietReporter {}
+
+#[derive(Clone)]
+struct VerboseReporter {}
+
+impl Reporter for VerboseReporter {
+    fn run_started(&self, title: Option<&str>, seed: Seed, example_count: u32) {
+        let name = title_text(title);
+        eprintln!(
+            "Monkey test property{name} started with {example_count} \
+            examples and seed {seed}."
+        );
+    }
+
+    fn example_passed(&self, index: u64) {
+        eprintln!("Example #{index} passed.");
+    }
+
+    fn failure_found(&self, example: &dyn Debug, reason: &str) {
+        eprintln!("Failure found: {example:?}\nReason: {reason}");
+    }
+
+    fn shrink_step(&self, step: u64, example: &dyn Debug, reason: &str) {
+        eprintln!("Shrink step #{step}: {example:?}\nReason: {reason}");
+    }
+
+    fn run_finished(
+        &self,
+        success_count: u64,
+        shrink_count: u64,
+        failed: bool,
+    ) {
+        if failed {
+            eprintln!(
+                "Monkey test property failed after {success_count} \
+                successful examples and {shrink_count} shrink steps."
+            );
+        } else {
+            eprintln!(
+                "Monkey test property passed all {success_count} examples."
+            );
+        }
+    }
+}
+
+struct ProgressReporter {
+    example_count: AtomicU32,
+}
+
+impl Clone for ProgressReporter {
+    fn clone(&self) -> Self {
+        ProgressReporter {
+            example_count: AtomicU32::new(
+                self.example_count.load(Ordering::Relaxed),
+            ),
+        }
+    }
+}
+
+impl Reporter for ProgressReporter {
+    fn run_started(
+        &self,
+        title: Option<&str>,
+        _seed: Seed,
+        example_count: u32,
+    ) {
+        self.example_count.store(example_count, Ordering::Relaxed);
+        let name = title_text(title);
+        eprintln!(
+            "Monkey test property{name}: testing {example_count} examples"
+        );
+    }
+
+    fn example_passed(&self, index: u64) {
+        let total = self.example_count.load(Ordering::Relaxed) as u64;
+        let passed = index + 1;
+        let tenth = (total / 10).max(1);
+
+        if passed % tenth == 0 {
+            eprintln!("Monkey test progress: {passed}/{total} examples passed");
+        }
+    }
+
+    fn failure_found(&self, _example: &dyn Debug, _reason: &str) {
+        eprintln!("Monkey test progress: failure found, shrinking");
+    }
+
+    fn shrink_step(&self, step: u64, _example: &dyn Debug, _reason: &str) {
+        if step % 100 == 0 {
+            eprintln!("Monkey test progress: {step} shrink steps taken");
+        }
+    }
+
+    fn run_finished(
+        &self,
+        success_count: u64,
+        shrink_count: u64,
+        failed: bool,
+    ) {
+        if failed {
+            eprintln!(
+                "Monkey test progress: failed after {success_count} examples \
+                and {shrink_count} shrink steps"
+            );
+        } else {
+            eprintln!("Monkey test progress: done");
+        }
+    }
+}
+
+fn title_text(title: Option<&str>) -> String {
+    title.map(|t| format!(" \"{t}\"")).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::fmt::Debug;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    /// Reporter recording all events as text, for later inspection.
+    #[derive(Clone)]
+    struct RecordingReporter {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Reporter for RecordingReporter {
+        fn run_started(
+            &self,
+            title: Option<&str>,
+            seed: Seed,
+            example_count: u32,
+        ) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("started {title:?} {seed} {example_count}"));
+        }
+
+        fn example_passed(&self, index: u64) {
+            self.events.lock().unwrap().push(format!("passed {index}"));
+        }
+
+        fn failure_found(&self, example: &dyn Debug, reason: &str) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("failure {example:?} {reason}"));
+        }
+
+        fn shrink_step(&self, step: u64, example: &dyn Debug, _reason: &str) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("shrink {step} {example:?}"));
+        }
+
+        fn run_finished(
+            &self,
+            success_count: u64,
+            shrink_count: u64,
+            failed: bool,
+        ) {
+            self.events.lock().unwrap().push(format!(
+                "finished {success_count} {shrink_count} {failed}"
+            ));
+        }
+    }
+
+    #[test]
+    fn reporter_receives_all_events_in_order() {
+        let events = Arc::new(Mutex::new(vec![]));
+
+        monkey_test()
+            .with_seed(1337)
+            .with_example_count(10)
+            .with_reporter(Box::new(RecordingReporter {
+                events: events.clone(),
+            }))
+            .with_generator(gens::fixed::sequence(&[1, 2, 7]))
+            .with_shrinker(shrinks::int_to_zero())
+            .title("small")
+            .test_true(|x| x < 5)
+            .assert_minimum_failure(5);
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "started Some(\"small\") 1337 10",
+                "passed 0",
+                "passed 1",
+                "failure 7 Expecting 'true' but got 'false'.",
+                "shrink 1 6",
+                "shrink 2 5",
+                "finished 2 2 true",
+            ]
+        );
+    }
+
+    #[test]
+    fn reporter_is_told_about_successful_run() {
+        let events = Arc::new(Mutex::new(vec![]));
+
+        monkey_test()
+            .with_example_count(2)
+            .with_reporter(Box::new(RecordingReporter {
+                events: events.clone(),
+            }))
+            .with_generator(gens::fixed::constant(1))
+            .assert_true(|x| x == 1);
+
+        assert_eq!(
+            events.lock().unwrap().last().unwrap(),
+            "finished 2 0 false"
+        );
+    }
+
+    #[test]
+    fn configuration_can_be_shared_between_threads() {
+        let conf = monkey_test().with_reporter(super::progress());
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                conf.with_generator(gens::u8::any()).assert_true(|_| true);
+            });
+        });
+    }
+
+    /// Reporter giving a short failure message of its own.
+    #[derive(Clone)]
+    struct ShortMessageReporter {}
+
+    impl Reporter for ShortMessageReporter {
+        fn assert_failed(&self, failure: &super::FailureReport) -> String {
+            format!(
+                "{} failed for {} with seed {}",
+                failure.title.unwrap_or("?"),
+                failure.minimum_failure,
+                failure.seed
+            )
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "small failed for 5 with seed 1337")]
+    fn reporter_gives_failure_message_of_assert() {
+        monkey_test()
+            .with_seed(1337)
+            .with_reporter(Box::new(ShortMessageReporter {}))
+            .with_generator(gens::u8::any())
+            .title("small")
+            .assert_true(|x| x < 5);
+    }
+
+    #[test]
+    fn built_in_reporters_do_not_affect_result() {
+        for reporter in [super::quiet(), super::verbose(), super::progress()] {
+            monkey_test()
+                .with_reporter(reporter)
+                .with_generator(gens::u8::any())
+                .test_true(|x| x < 100)
+                .assert_minimum_failure(100);
+        }
+    }
+}
@@ -1,7 +1,13 @@
+use crate::internal::isolation;
 use crate::BoxShrink;
 use crate::ConfAndGen;
+use crate::Reporter;
 use crate::Seed;
+use std::cell::Cell;
+use std::collections::HashSet;
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::time::Duration;
 
 /// Result summary from evaluation of a property tested.
 #[derive(Debug, PartialEq)]
@@ -45,9 +51,60 @@ pub enum MonkeyResult<E> {
         /// [minimum failure](self::MonkeyResult#variant.MonkeyErr.field.minimum_failure)
         /// example. Other failures can have other reasons not shown here.
         reason: String,
+
+        /// True if the property sometimes passed when re-running the minimum
+        /// failure, which indicates that the property do not only depend on
+        /// the example. See [crate::Conf::with_failure_reruns].
+        flaky: bool,
+
+        /// Further explanation of the
+        /// [reason](self::MonkeyResult#variant.MonkeyErr.field.reason), or
+        /// empty if there is none. For `assert_eq` and `test_eq`, this is the
+        /// paths of the differing parts and a line based diff of the
+        /// pretty-printed values, if they are printed on several lines.
+        explanation: String,
     },
 }
 
+/// Failure of a single evaluated example.
+#[derive(Clone, Debug)]
+pub struct Failure {
+    /// See [MonkeyResult#variant.MonkeyErr.field.reason].
+    pub reason: String,
+    /// See [MonkeyResult#variant.MonkeyErr.field.explanation].
+    pub explanation: String,
+    /// True if the example did not finish in time, see
+    /// [crate::Conf::with_example_timeout].
+    pub timed_out: bool,
+}
+
+impl Failure {
+    /// Failure of an example not finishing within given timeout.
+    pub fn timeout(timeout: Duration) -> Self {
+        Failure {
+            reason: format!(
+                "Expecting no timeout, but timed out after {timeout:?}."
+            ),
+            explanation: String::new(),
+            timed_out: true,
+        }
+    }
+}
+
+impl From<String> for Failure {
+    fn from(reason: String) -> Self {
+        Failure {
+            reason,
+            explanation: String::new(),
+            timed_out: false,
+        }
+    }
+}
+
+/// Maximum number of examples that may time out for each evaluated property,
+/// since each timed out example takes at least the timeout to evaluate.
+const MAX_TIMED_OUT_EXAMPLES: u32 = 20;
+
 impl<E> MonkeyResult<E> {
     /// Verify that the result is a failure and that the minimum failure equals
     /// given argument `expected_minimum_failure`.
@@ -80,22 +137,103 @@ impl<E> MonkeyResult<E> {
 
 pub fn evaluate_property<E, P>(cg: &ConfAndGen<E>, prop: P) -> MonkeyResult<E>
 where
-    E: Clone + 'static,
-    P: Fn(E) -> Result<(), String>,
+    E: Debug + Clone + 'static,
+    P: Fn(E) -> Result<(), Failure>,
 {
+    if cg.conf.process_isolation {
+        isolation::start_evaluation();
+
+        if let Some(target) = isolation::child_target() {
+            let result = match example_at_path(cg, target.seed, &target.path) {
+                Some(example) => prop(example),
+                None => {
+                    Err(String::from("Example not found in child process.")
+                        .into())
+                }
+            };
+            isolation::report_and_exit(result);
+        }
+    }
+
+    // Count timed out examples, to stop evaluating examples when too many
+    // have timed out.
+    let timed_out_count = Cell::new(0);
+    let has_too_many_timeouts =
+        || timed_out_count.get() >= MAX_TIMED_OUT_EXAMPLES;
+
+    // Evaluate example, given the path to the example.
+    let check = |path: &[usize], example: E| {
+        let result = if cg.conf.process_isolation {
+            isolation::evaluate_in_child_process(
+                cg.conf.seed,
+                path,
+                cg.conf.example_timeout,
+            )
+        } else {
+            prop(example)
+        };
+        if result.as_ref().is_err_and(|failure| failure.timed_out) {
+            timed_out_count.set(timed_out_count.get() + 1);
+        }
+        result
+    };
+
+    // Count how many times given failing example passes when re-run.
+    let reruns = cg.conf.failure_reruns;
+    let count_passing_reruns = |path: &[usize], example: &E| {
+        (0..reruns)
+            .take_while(|_| !has_too_many_timeouts())
+            .filter(|_| check(path, example.clone()).is_ok())
+            .count()
+    };
+
+    // Only regard shrink candidates failing consistently as failures.
+    let consistent_check = |path: &[usize], example: E| {
+        let result = check(path, example.clone());
+        if result.is_err() && count_passing_reruns(path, &example) > 0 {
+            Ok(())
+        } else {
+            result
+        }
+    };
+
+    let reporter = &cg.conf.reporter;
+    reporter.run_started(
+        cg.title.as_deref(),
+        cg.conf.seed,
+        cg.conf.example_count,
+    );
+
     let mut it = cg.generator.examples(cg.conf.seed, cg.conf.size.clone());
 
     for i in 0..cg.conf.example_count {
         let example = it.next();
 
-        let (first_example, maybe_first_reason) = match example {
-            Some(e) => (e.clone(), prop(e.clone())),
+        let (first_example, maybe_first_failure) = match example {
+            Some(e) => (e.clone(), check(&[i as usize], e.clone())),
             None => panic!("To few examples. Only got {i}"),
         };
 
-        if let Err(first_reason) = maybe_first_reason {
-            let shrinked_values =
-                do_shrink(prop, first_example.clone(), cg.generator.shrinker());
+        if let Err(first_failure) = maybe_first_failure {
+            reporter.failure_found(&first_example, &first_failure.reason);
+
+            let (shrinked_values, minimum_path) = do_shrink(
+                consistent_check,
+                vec![i as usize],
+                first_example.clone(),
+                cg.generator.shrinker(),
+                &ShrinkSearch {
+                    effort: 10_000,
+                    backtracking_depth: cg.conf.shrink_backtracking_depth,
+                    backtracking_width: cg.conf.shrink_backtracking_width,
+                    is_stopped: &has_too_many_timeouts,
+                },
+                match cg.memo {
+                    Some(new_memo) => new_memo(),
+                    None => Box::new(()),
+                },
+                reporter.as_ref(),
+            );
 
             // All but last shrinked value, up to a max limit
             let other_count = shrinked_values.len().clamp(1, 100) as u64 - 1;
@@ -106,52 +244,295 @@ where
                 .take(other_count as usize)
                 .collect::<Vec<_>>();
 
-            let (minimum_failure, minimum_reason) = shrinked_values
+            let (minimum_failure, mut minimum_reason) = shrinked_values
                 .last()
                 .cloned()
-                .unwrap_or((first_example.clone(), first_reason));
+                .unwrap_or((first_example.clone(), first_failure));
+
+            if has_too_many_timeouts() {
+                minimum_reason.reason += &format!(
+                    " Shrinking stopped early, since \
+                    {MAX_TIMED_OUT_EXAMPLES} examples timed out."
+                );
+            }
+
+            let flaky =
+                count_passing_reruns(&minimum_path, &minimum_failure) > 0;
+
+            let shrink_count = shrinked_values.len() as u64;
+            reporter.run_finished(i as u64, shrink_count, true);
 
             return MonkeyResult::<E>::MonkeyErr {
                 minimum_failure,
                 original_failure: first_example,
                 some_other_failures,
                 success_count: i as u64,
-                shrink_count: shrinked_values.len() as u64,
+                shrink_count,
                 seed: cg.conf.seed,
                 title: cg.title.clone(),
-                reason: minimum_reason,
+                reason: minimum_reason.reason,
+                flaky,
+                explanation: minimum_reason.explanation,
             };
         }
+
+        reporter.example_passed(i as u64);
     }
 
+    reporter.run_finished(cg.conf.example_count as u64, 0, false);
     MonkeyResult::<E>::MonkeyOk()
 }
 
-fn do_shrink<E, P>(
-    prop: P,
-    original_failure: E,
+/// Memory of examples already tried while shrinking, see
+/// [crate::ConfAndGen::with_shrink_memoization].
+pub trait Memo<E> {
+    /// Register example as tried, returning false if already tried.
+    fn is_new(&mut self, example: &E) -> bool;
+}
+
+impl<E> Memo<E> for HashSet<E>
+where
+    E: Hash + Eq + Clone,
+{
+    fn is_new(&mut self, example: &E) -> bool {
+        self.insert(example.clone())
+    }
+}
+
+/// Memo not remembering any example, regarding all examples as new.
+impl<E> Memo<E> for () {
+    fn is_new(&mut self, _example: &E) -> bool {
+        true
+    }
+}
+
+/// Constructor of an empty memo.
+pub type NewMemo<E> = fn() -> Box<dyn Memo<E>>;
+
+/// Create memo remembering examples in a hash set.
+pub fn new_memo<E>() -> Box<dyn Memo<E>>
+where
+    E: Hash + Eq + Clone + 'static,
+{
+    Box::new(HashSet::new())
+}
+
+/// Find the example at given path, which is the index of the generated
+/// example followed by the index of the chosen shrink candidate in each shrink
+/// step.
+fn example_at_path<E>(
+    cg: &ConfAndGen<E>,
+    seed: Seed,
+    path: &[usize],
+) -> Option<E>
+where
+    E: Clone + 'static,
+{
+    let (first, rest) = path.split_first()?;
+    let mut example = cg
+        .generator
+        .examples(seed, cg.conf.size.clone())
+        .nth(*first)?;
+    let shrinker = cg.generator.shrinker();
+
+    for index in rest {
+        example = shrinker.candidates(example).nth(*index)?;
+    }
+    Some(example)
+}
+
+/// Bounds of the search for a minimum failure.
+struct ShrinkSearch<'a> {
+    /// Maximum number of candidates to evaluate in each part of the search.
+    effort: usize,
+    /// See [crate::Conf::with_shrink_backtracking].
+    backtracking_depth: usize,
+    /// See [crate::Conf::with_shrink_backtracking].
+    backtracking_width: usize,
+    /// True if no more examples should be evaluated at all, ending the
+    /// search at once.
+    is_stopped: &'a dyn Fn() -> bool,
+}
+
+/// State of an ongoing search for a minimum failure.
+struct Shrinking<'a, E, P> {
+    check: P,
     shrinker: BoxShrink<E>,
-) -> Vec<(E, String)>
+    reporter: &'a dyn Reporter,
+    /// True if no more examples should be evaluated at all.
+    is_stopped: &'a dyn Fn() -> bool,
+    /// Already tried examples, not to be evaluated again.
+    tried: Box<dyn Memo<E>>,
+    /// Number of candidates left to evaluate in current part of the search.
+    budget: usize,
+    /// Number of candidates left to draw from shrinkers, including already
+    /// tried candidates, guarding against endless repetition of candidates.
+    draws_left: usize,
+    /// Accepted shrink steps, with failure.
+    shrinked_examples: Vec<(E, Failure)>,
+    /// The smallest failure found so far and the path to it.
+    current: E,
+    current_path: Vec<usize>,
+}
+
+impl<E, P> Shrinking<'_, E, P>
 where
-    E: Clone,
-    P: Fn(E) -> Result<(), String>,
+    E: Debug + Clone,
+    P: Fn(&[usize], E) -> Result<(), Failure>,
 {
-    let shrink_effort = 10_000;
-    let mut shrinked_examples = vec![];
-    let mut candidates = shrinker.candidates(original_failure);
-
-    for _ in 0..shrink_effort {
-        match candidates.next() {
-            Some(candidate) => {
-                let maybe_failure_reason = prop(candidate.clone());
-                if let Err(reason) = maybe_failure_reason {
-                    shrinked_examples.push((candidate.clone(), reason));
-                    candidates = shrinker.candidates(candidate);
+    /// Renew budget and draws for a new part of the search.
+    fn renew(&mut self, effort: usize) {
+        self.budget = effort;
+        self.draws_left = effort * 100;
+    }
+
+    /// True if no more candidates can be drawn or evaluated.
+    fn is_exhausted(&self) -> bool {
+        self.budget == 0 || self.draws_left == 0 || (self.is_stopped)()
+    }
+
+    /// Evaluate example if not already tried and within budget, returning
+    /// the failure if the example fails.
+    fn try_example(&mut self, example: &E, path: &[usize]) -> Option<Failure> {
+        self.draws_left -= 1;
+        if !self.tried.is_new(example) {
+            return None;
+        }
+        self.budget -= 1;
+        (self.check)(path, example.clone()).err()
+    }
+
+    fn accept(&mut self, example: E, path: Vec<usize>, failure: Failure) {
+        let step = self.shrinked_examples.len() as u64 + 1;
+        self.reporter.shrink_step(step, &example, &failure.reason);
+        self.shrinked_examples.push((example.clone(), failure));
+        self.current = example;
+        self.current_path = path;
+    }
+
+    /// Find first failing single-step candidate of current example.
+    fn first_failing_candidate(&mut self) -> Option<(E, Vec<usize>, Failure)> {
+        let candidates = self.shrinker.candidates(self.current.clone());
+
+        for (index, candidate) in candidates.enumerate() {
+            if self.is_exhausted() {
+                return None;
+            }
+            let path = extended(&self.current_path, index);
+            if let Some(reason) = self.try_example(&candidate, &path) {
+                return Some((candidate, path, reason));
+            }
+        }
+        None
+    }
+
+    /// Greedily accept the first failing candidate, until no candidate fails.
+    fn descend(&mut self) {
+        while let Some((example, path, reason)) = self.first_failing_candidate()
+        {
+            self.accept(example, path, reason);
+        }
+    }
+
+    /// Beam search through passing candidates of the current example, looking
+    /// for a failure further down the shrink tree. Only the first `width`
+    /// candidates on each level are explored, down to `depth` levels.
+    fn search_beyond_passing(
+        &mut self,
+        depth: usize,
+        width: usize,
+    ) -> Option<(E, Vec<usize>, Failure)> {
+        let mut level = vec![(self.current.clone(), self.current_path.clone())];
+
+        for _ in 0..depth {
+            let mut next_level = vec![];
+
+            for (example, path) in level {
+                let candidates = self.shrinker.candidates(example);
+
+                for (index, candidate) in candidates.enumerate() {
+                    if self.is_exhausted() {
+                        return None;
+                    }
+                    let path = extended(&path, index);
+                    if let Some(reason) = self.try_example(&candidate, &path) {
+                        return Some((candidate, path, reason));
+                    }
+                    if next_level.len() < width {
+                        next_level.push((candidate, path));
+                    }
                 }
             }
-            None => return shrinked_examples,
+            level = next_level;
+        }
+        None
+    }
+}
+
+/// Copy of path, extended with given index.
+fn extended(path: &[usize], index: usize) -> Vec<usize> {
+    let mut path = path.to_vec();
+    path.push(index);
+    path
+}
+
+/// Search for a minimum failure, returning the accepted shrink steps and the
+/// path to the minimum failure.
+///
+/// The search greedily accepts the first failing candidate and restarts from
+/// it. When no candidate fails, optionally a bounded beam search through
+/// passing candidates is made, restarting the greedy search on any failure
+/// found. If the effort of the search was exhausted, a final minimality check
+/// with renewed effort tries the candidates of the result.
+fn do_shrink<'a, E, P>(
+    check: P,
+    original_path: Vec<usize>,
+    original_failure: E,
+    shrinker: BoxShrink<E>,
+    search: &ShrinkSearch<'a>,
+    tried: Box<dyn Memo<E>>,
+    reporter: &dyn Reporter,
+) -> (Vec<(E, Failure)>, Vec<usize>)
+where
+    E: Debug + Clone,
+    P: Fn(&[usize], E) -> Result<(), Failure>,
+{
+    let mut shrinking = Shrinking {
+        check,
+        shrinker,
+        reporter,
+        is_stopped: search.is_stopped,
+        tried,
+        budget: 0,
+        draws_left: 0,
+        shrinked_examples: vec![],
+        current: original_failure.clone(),
+        current_path: original_path,
+    };
+    shrinking.renew(search.effort);
+    shrinking.tried.is_new(&original_failure);
+
+    loop {
+        shrinking.descend();
+
+        match shrinking.search_beyond_passing(
+            search.backtracking_depth,
+            search.backtracking_width,
+        ) {
+            Some((example, path, reason)) => {
+                shrinking.accept(example, path, reason)
+            }
+            None => break,
         }
     }
 
-    shrinked_examples
+    // Minimality check, with renewed effort, making sure no single-step
+    // candidate of the result fails, also when the effort is exhausted. When
+    // not exhausted, all candidates of the result have already been tried.
+    if shrinking.is_exhausted() {
+        shrinking.renew(search.effort);
+        shrinking.descend();
+    }
+
+    (shrinking.shrinked_examples, shrinking.current_path)
 }
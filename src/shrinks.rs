@@ -1,6 +1,8 @@
 //! The `shrinks` module contains built in shrinkers.
 
 mod bool;
+mod bytes;
+mod char;
 mod filter;
 pub mod fixed;
 mod float;
@@ -13,14 +15,51 @@ mod zip;
 
 pub use bool::bool;
 pub use bool::bool_to_true;
+pub use bytes::bytes;
+pub use char::char;
+pub use char::char_in;
 pub use filter::filter;
+pub(crate) use filter::keep_only;
 pub use float::float;
+pub(crate) use float::float_and_nans;
 pub use from_fn::from_fn;
 pub use from_fn::from_fn_boxed;
 
 pub use integer::int_in_range;
+pub use integer::int_pair_in_range;
 pub use integer::int_to_zero;
 
 pub use map::map;
 pub use no_shrink::none;
+pub(crate) use zip::interleave;
 pub use zip::zip;
+pub use zip::zip_3;
+pub use zip::zip_4;
+pub use zip::zip_5;
+pub use zip::zip_6;
+
+/// Macro to generate code for all float type modules
+macro_rules! float_module {
+    ($name:ident) => {
+        /// Shrinkers for values of module type.
+        pub mod $name {
+            use crate::BoxShrink;
+
+            /// Float shrinker shrinking towards `+0.0`, like
+            /// [crate::shrinks::float], which also shrinks NaNs toward
+            /// simpler NaNs.
+            ///
+            /// NaNs with distinct payloads and sign bits are told apart.
+            /// When no other value will do, a NaN shrinks to a positive NaN,
+            /// a signalling NaN shrinks to a quiet NaN and the payload
+            /// shrinks toward zero. The simplest NaN is the quiet, positive
+            /// NaN without payload, like `NAN`.
+            pub fn float() -> BoxShrink<$name> {
+                super::float_and_nans()
+            }
+        }
+    };
+}
+
+float_module!(f32);
+float_module!(f64);
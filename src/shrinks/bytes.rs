@@ -0,0 +1,22 @@
+use crate::BoxShrink;
+
+/// Byte vector shrinker, removing ranges of bytes and then shrinking bytes
+/// toward zero. Bytes are never moved, since the meaning of a byte in a
+/// binary format usually depends on its position.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// let candidates = shrinks::bytes()
+///     .candidates(vec![0xCA, 0xFE])
+///     .take(4)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     candidates,
+///     vec![vec![], vec![0xFE], vec![0xCA], vec![0x00, 0xFE]]
+/// );
+/// ```
+pub fn bytes() -> BoxShrink<Vec<u8>> {
+    crate::shrinks::vec::positional(crate::shrinks::int_to_zero())
+}
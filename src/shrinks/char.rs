@@ -0,0 +1,110 @@
+use crate::internal::char_rank::from_rank;
+use crate::internal::char_rank::rank;
+use crate::internal::char_rank::MAX_RANK;
+use crate::BoxIter;
+use crate::BoxShrink;
+use std::ops::RangeInclusive;
+
+/// Char shrinker, shrinking toward simpler chars. The simplest char is 'a',
+/// followed by other lower case letters, upper case letters, digits, space,
+/// other printable ASCII chars, ASCII control chars and last all other chars.
+pub fn char() -> BoxShrink<char> {
+    char_in(&['\0'..=char::MAX])
+}
+
+/// Char shrinker, like [char()], that only shrinks to chars in given ranges.
+/// Chars shrink toward the simplest char in the ranges, like 'A' for the
+/// range 'A'..='Z'.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// let shrinker = shrinks::char_in(&['A'..='Z']);
+///
+/// assert_eq!(shrinker.candidates('Q').next(), Some('A'));
+/// ```
+pub fn char_in(ranges: &[RangeInclusive<char>]) -> BoxShrink<char> {
+    let ranges = ranges.to_vec();
+    let simplest = ranges
+        .iter()
+        .filter_map(|range| simplest_in(range.clone()))
+        .min();
+
+    crate::shrinks::from_fn_boxed(move |original: char| {
+        let original_rank = rank(original);
+        let ranges = ranges.clone();
+
+        match simplest {
+            Some(target)
+                if original_rank > target && contains(&ranges, original) =>
+            {
+                Box::new(
+                    crate::shrinks::int_in_range(target, MAX_RANK)
+                        .candidates(original_rank)
+                        .filter_map(from_rank)
+                        .filter(move |c| contains(&ranges, *c)),
+                )
+            }
+            _ => Box::new(std::iter::empty()) as BoxIter<char>,
+        }
+    })
+}
+
+/// Lowest rank of chars in range. Only ASCII chars are out of code point
+/// order, so any simpler char is either ASCII or the first char of the range.
+fn simplest_in(range: RangeInclusive<char>) -> Option<u32> {
+    (0..128u32)
+        .filter_map(char::from_u32)
+        .chain(std::iter::once(*range.start()))
+        .filter(|c| range.contains(c))
+        .map(rank)
+        .min()
+}
+
+fn contains(ranges: &[RangeInclusive<char>], c: char) -> bool {
+    ranges.iter().any(|range| range.contains(&c))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::testing::assert_iter_eq;
+
+    #[test]
+    fn shrinks_toward_lower_case_a() {
+        assert_iter_eq(
+            super::char().candidates('\0').take(3),
+            vec!['a', 'W', '*'],
+            "should bisect toward 'a' by rank",
+        );
+    }
+
+    #[test]
+    fn shrinks_within_ranges() {
+        let shrinker = super::char_in(&['0'..='9', 'A'..='F']);
+
+        assert_iter_eq(
+            shrinker.candidates('7'),
+            vec![
+                'A', '3', '5', '6', '5', '4', '3', '2', '1', '0', 'F', 'E',
+                'D', 'C', 'B',
+            ],
+            "should shrink toward 'A' and only to chars in ranges",
+        );
+    }
+
+    #[test]
+    fn no_shrinking_of_simplest_char_or_chars_out_of_ranges() {
+        let shrinker = super::char_in(&['A'..='Z']);
+
+        assert_iter_eq(
+            shrinker.candidates('A'),
+            vec![],
+            "simplest char should not shrink",
+        );
+        assert_iter_eq(
+            shrinker.candidates('!'),
+            vec![],
+            "char out of ranges should not shrink",
+        );
+    }
+}
@@ -32,6 +32,24 @@ where
     })
 }
 
+/// Like [filter], but without complaining about heavy filtering. For
+/// shrinkers where most candidates are filtered out by design, like when
+/// shrinking NaNs with a general float shrinker.
+pub(crate) fn keep_only<E, P>(
+    original_shrink: BoxShrink<E>,
+    predicate: P,
+) -> BoxShrink<E>
+where
+    E: Clone + 'static,
+    P: Fn(&E) -> bool + Clone + 'static,
+{
+    crate::shrinks::from_fn(move |original: E| {
+        original_shrink
+            .candidates(original)
+            .filter(predicate.clone())
+    })
+}
+
 #[cfg(test)]
 mod test {
     use crate::testing::assert_iter_eq;
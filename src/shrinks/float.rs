@@ -1,3 +1,4 @@
+use crate::gens::float_parts::FloatParts;
 use crate::shrinks;
 use crate::BoxShrink;
 use num_traits::Float;
@@ -9,6 +10,10 @@ use num_traits::Float;
 ///
 /// `NaN`, `-Inf`, `Inf`, `Min`, `Max`, ...., `-100`, `100`, ..., `-10`, `10`,
 ///  ..., `-1`, `1`, ..., `-0`, `0`.
+///
+/// A NaN only shrinks to values other than NaN. To also shrink NaNs toward
+/// simpler NaNs, use [crate::shrinks::f32::float] or
+/// [crate::shrinks::f64::float] instead.
 pub fn float<F>() -> BoxShrink<F>
 where
     F: Float + std::fmt::Debug + Clone + 'static,
@@ -23,13 +28,65 @@ where
         let finites = finite_values(finite_original);
         let specials = special_values(original);
 
-        specials.clone().into_iter().chain(finites.clone())
+        specials.into_iter().chain(finites)
     })
 }
 
+/// Float shrinker like [float], which also shrinks NaNs toward simpler NaNs.
+///
+/// NaNs with distinct payloads and sign bits are told apart. When no other
+/// value will do, a NaN shrinks to a positive NaN, a signalling NaN shrinks to
+/// a quiet NaN and the payload shrinks toward zero. The simplest NaN is the
+/// quiet, positive NaN without payload, like [f64::NAN].
+pub(crate) fn float_and_nans<F>() -> BoxShrink<F>
+where
+    F: Float + FloatParts + Clone + 'static,
+{
+    shrinks::from_fn(move |original: F| {
+        float().candidates(original).chain(nan_values(original))
+    })
+}
+
+/// Simpler NaNs than given original, if it is a NaN.
+fn nan_values<F>(original: F) -> impl Iterator<Item = F>
+where
+    F: Float + FloatParts + 'static,
+{
+    let quiet_bit = F::quiet_nan_bit();
+    let is_negative = original.is_sign_negative();
+    let is_quiet = original.fraction() & quiet_bit != 0;
+    let payload = original.fraction() & !quiet_bit;
+    let nan = move |is_negative, is_quiet, payload| {
+        let quiet = if is_quiet { quiet_bit } else { 0 };
+        F::from_bits(F::compose(
+            is_negative,
+            F::exponent_max(),
+            quiet | payload,
+        ))
+    };
+
+    let positive = (original.is_nan() && is_negative)
+        .then(|| nan(false, is_quiet, payload));
+    let quiet = (original.is_nan() && !is_quiet)
+        .then(|| nan(is_negative, true, payload));
+    // A signalling NaN needs some payload, not to become an infinity.
+    let min_payload = if is_quiet { 0 } else { 1 };
+    let payloads = original
+        .is_nan()
+        .then(|| {
+            shrinks::int_in_range(min_payload, quiet_bit - 1)
+                .candidates(payload)
+        })
+        .into_iter()
+        .flatten()
+        .map(move |payload| nan(is_negative, is_quiet, payload));
+
+    positive.into_iter().chain(quiet).chain(payloads)
+}
+
 fn special_values<F>(original: F) -> Vec<F>
 where
-    F: Float + std::fmt::Debug + Clone + 'static,
+    F: Float + Clone + 'static,
 {
     let mut values = Vec::<F>::new();
     if original.is_nan() {
@@ -41,9 +98,9 @@ where
     values
 }
 
-fn finite_values<F>(original: F) -> impl Iterator<Item = F> + Clone
+fn finite_values<F>(original: F) -> impl Iterator<Item = F>
 where
-    F: Float + std::fmt::Debug + Clone + 'static,
+    F: Float + Clone + 'static,
 {
     let mut subtraction = original;
     let mut old_result = original;
@@ -78,6 +135,60 @@ mod test {
         )
     }
 
+    #[test]
+    fn should_shrink_nan_toward_positive_quiet_nan_without_payload() {
+        let negative_signalling_nan = f32::from_bits(0xFF80_0005);
+
+        let nan_candidates = crate::shrinks::f32::float()
+            .candidates(negative_signalling_nan)
+            .filter(|f: &f32| f.is_nan())
+            .map(f32::to_bits)
+            .take(4);
+
+        assert_iter_eq(
+            nan_candidates,
+            vec![0x7F80_0005, 0xFFC0_0005, 0xFF80_0001, 0xFF80_0003],
+            "should shrink sign, quiet bit and then payload of NaN",
+        )
+    }
+
+    #[test]
+    fn should_not_shrink_simplest_nan_to_other_nan() {
+        assert_eq!(
+            crate::shrinks::f64::float()
+                .candidates(f64::NAN)
+                .filter(|f| f.is_nan())
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn generic_shrinker_should_not_shrink_nan_to_other_nan() {
+        let negative_signalling_nan = f64::from_bits(0xFFF0_0000_0000_0005);
+
+        assert_eq!(
+            super::float()
+                .candidates(negative_signalling_nan)
+                .filter(|f| f.is_nan())
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn shrinker_is_available_for_code_generic_over_floats() {
+        fn first_candidate<F>(original: F) -> Option<F>
+        where
+            F: num_traits::Float + std::fmt::Debug + Clone + 'static,
+        {
+            super::float().candidates(original).next()
+        }
+
+        assert_eq!(first_candidate(-1.5f64), Some(-0.0));
+        assert_eq!(first_candidate(-1.5f32), Some(-0.0));
+    }
+
     #[test]
     fn shrinker_should_always_terminate() {
         monkey_test()
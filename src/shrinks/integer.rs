@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 
+use crate::BoxIter;
 use crate::BoxShrink;
 use num_traits::PrimInt;
 
@@ -12,16 +13,91 @@ where
 }
 
 /// Shrink integer types towards the value zero if in range, or other value
-/// nearest zero within range of min and max.
+/// nearest zero within range of min and max. Examples out of range have no
+/// shrink candidates.
 pub fn int_in_range<E>(min: E, max: E) -> BoxShrink<E>
 where
     E: PrimInt + std::fmt::Debug + 'static,
 {
-    crate::shrinks::from_fn(move |original| {
-        eager(original, min, max).chain(decrement(original, min, max))
+    crate::shrinks::from_fn_boxed(move |original| {
+        if original < min || max < original {
+            return Box::new(std::iter::empty());
+        }
+
+        let target = shrink_target(min, max);
+        // Eager shrinking already tries the target, unless the original is
+        // next to it.
+        let target_is_tried = !is_within_one(original - target);
+
+        Box::new(
+            eager(original, min, max).chain(
+                decrement(original, min, max)
+                    .filter(move |e| !(target_is_tried && *e == target)),
+            ),
+        )
     })
 }
 
+/// Shrink pairs of integers like [crate::shrinks::zip] of two
+/// [int_in_range] shrinkers, but first shift both integers together toward
+/// the shrink target, keeping the difference between them. This helps
+/// failures depending on the difference between the integers, where
+/// shrinking either integer alone changes the difference.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// let shrinker = shrinks::int_pair_in_range(1, 1000);
+///
+/// assert_eq!(shrinker.candidates((500, 507)).next(), Some((1, 8)));
+/// ```
+pub fn int_pair_in_range<E>(min: E, max: E) -> BoxShrink<(E, E)>
+where
+    E: PrimInt + std::fmt::Debug + 'static,
+{
+    let pairs =
+        crate::shrinks::zip(int_in_range(min, max), int_in_range(min, max));
+
+    crate::shrinks::from_fn(move |(x, y): (E, E)| {
+        shifted_together(x, y, min, max).chain(pairs.candidates((x, y)))
+    })
+}
+
+/// Candidates where both integers are shifted toward the shrink target by the
+/// same amount, as far as the integer nearest the target is shrunk. Integers
+/// on different sides of the shrink target are not shifted.
+fn shifted_together<E>(x: E, y: E, min: E, max: E) -> BoxIter<(E, E)>
+where
+    E: PrimInt + std::fmt::Debug + 'static,
+{
+    if x < min || max < x || y < min || max < y {
+        return Box::new(std::iter::empty());
+    }
+
+    let target = shrink_target(min, max);
+    let nearest = if target <= x && target <= y {
+        x.min(y)
+    } else if x <= target && y <= target {
+        x.max(y)
+    } else {
+        return Box::new(std::iter::empty());
+    };
+
+    Box::new(
+        int_in_range(min, max)
+            .candidates(nearest)
+            // Only candidates between the target and the nearest integer
+            // keep both integers on the same side of the target.
+            .filter(move |shifted| {
+                (target <= *shifted && *shifted < nearest)
+                    || (nearest < *shifted && *shifted <= target)
+            })
+            .map(move |shifted| {
+                ((x - nearest) + shifted, (y - nearest) + shifted)
+            }),
+    )
+}
+
 fn decrement<E: PrimInt + std::fmt::Debug>(
     original: E,
     min: E,
@@ -66,9 +142,10 @@ fn decrement<E: PrimInt + std::fmt::Debug>(
 }
 
 /// Eagerly try shrink down original failing example using bisection. Tries
-/// smaller an smaller decrements from original failure example down to
-/// decrement of two. Decrement one unnessesarily genereates the same values as
-/// the separate decrement iterator.
+/// the shrink target itself first, and then smaller an smaller decrements from
+/// original failure example down to decrement of two. Decrement one
+/// unnessesarily genereates the same values as the separate decrement
+/// iterator.
 ///
 /// Test out both the positive and negative complement (mirror) of candidate.
 /// Positive value is regarded as closer to target than negative value.
@@ -84,20 +161,18 @@ where
     let target = shrink_target(min, max);
 
     let two = E::one() + E::one();
-    let mut decrement = (original - target) / two;
+    let mut decrement = original - target;
 
     std::iter::from_fn(move || {
-        let is_neg_one =
-            decrement < E::zero() && (decrement + E::one()).is_zero();
-
         // No need to continue if decrement is small
-        if decrement.is_zero() || is_neg_one || decrement.is_one() {
+        if is_within_one(decrement) {
             None
         } else {
             let result = original - decrement;
             decrement = decrement / two;
 
-            let maybe_mirror_result = mirror_around(target, result);
+            let maybe_mirror_result = mirror_around(target, result)
+                .filter(|mirror| *mirror != result);
             maybe_mirror_result
                 .map(|mirror| vec![result, mirror])
                 .or(Some(vec![result]))
@@ -108,6 +183,14 @@ where
     .filter(move |e| min <= *e && *e <= max)
 }
 
+/// True if given difference is -1, 0 or 1.
+fn is_within_one<E: PrimInt>(difference: E) -> bool {
+    let is_neg_one =
+        difference < E::zero() && (difference + E::one()).is_zero();
+
+    difference.is_zero() || is_neg_one || difference.is_one()
+}
+
 /// Panics if example is out of range
 fn check_example_is_in_range<E: PrimInt + std::fmt::Debug>(
     example: E,
@@ -175,7 +258,7 @@ mod test {
 
     fn assert_within_range<E>(g: BoxGen<(E, E, E)>)
     where
-        E: PrimInt + std::fmt::Debug + std::panic::UnwindSafe + 'static,
+        E: PrimInt + std::fmt::Debug + std::panic::UnwindSafe + Send + 'static,
     {
         crate::monkey_test()
             .with_example_count(1000)
@@ -223,6 +306,24 @@ mod test {
         )
     }
 
+    #[test]
+    fn no_candidates_for_example_out_of_range() {
+        assert_iter_eq(
+            super::int_in_range(0, 16).candidates(1337),
+            vec![],
+            "should not shrink example out of range",
+        )
+    }
+
+    #[test]
+    fn shrinker_tries_target_only_once() {
+        assert_iter_eq(
+            super::int_to_zero().candidates(3),
+            vec![0, -2, 2, -1, 1],
+            "tries zero first, and not again at the end",
+        )
+    }
+
     #[test]
     fn decrement_can_shrink_both_positive_and_negative_numbers() {
         assert_iter_eq(
@@ -264,9 +365,9 @@ mod test {
     fn eager_tries_iteratively_smaller_decrement_both_positive_and_negative() {
         assert_iter_eq(
             super::eager(16, i64::MIN, i64::MAX),
-            vec![8, -8, 12, -12, 14, -14],
-            "shrinks toward zero, interleave positive and negative canidates \
-            if not restricted by given range",
+            vec![0, 8, -8, 12, -12, 14, -14],
+            "tries zero first and then shrinks toward zero, interleave \
+            positive and negative candidates if not restricted by given range",
         );
     }
 
@@ -275,7 +376,7 @@ mod test {
     ) {
         assert_iter_eq(
             super::eager(16, u64::MIN, u64::MAX),
-            vec![8, 12, 14],
+            vec![0, 8, 12, 14],
             "shrinks toward zero, with only positive canidates for unsigned \
             integer typed",
         );
@@ -285,30 +386,60 @@ mod test {
     fn eager_only_returns_candidates_within_range() {
         assert_iter_eq(
             super::eager(16, -5, i64::MAX),
-            vec![8, 12, 14],
+            vec![0, 8, 12, 14],
             "shrinks down toward zero, but only positives are in range",
         );
 
         assert_iter_eq(
             super::eager(17, 1, i64::MAX),
-            vec![9, 13, 15],
+            vec![1, 9, 13, 15],
             "shrink as in first but translated 1 to rateger 1 from 17, so \
             difference from original_failure to target is still 16",
         );
 
         assert_iter_eq(
             super::eager(-16, i64::MIN, 5),
-            vec![-8, -12, -14],
+            vec![0, -8, -12, -14],
             "same shrinking as in first assert, but only negative are in range.",
         );
 
         assert_iter_eq(
             super::eager(16, -12, i64::MAX),
-            vec![8, -8, 12, -12, 14],
+            vec![0, 8, -8, 12, -12, 14],
             "same shrinking as in first assert, but some (!) negative are in range.",
         );
     }
 
+    #[test]
+    fn pairs_are_shifted_together_keeping_difference() {
+        assert_iter_eq(
+            super::int_pair_in_range(1, 1000)
+                .candidates((17, 20))
+                .take(5),
+            vec![(1, 4), (9, 12), (13, 16), (15, 18), (16, 19)],
+            "shifts both integers as far as the nearest integer shrinks",
+        );
+
+        assert_iter_eq(
+            super::int_pair_in_range(i64::MIN, i64::MAX)
+                .candidates((-9, -7))
+                .take(3),
+            vec![(-2, 0), (-6, -4), (-8, -6)],
+            "shifts negative integers up toward zero",
+        );
+    }
+
+    #[test]
+    fn pairs_on_different_sides_of_target_are_not_shifted() {
+        assert_iter_eq(
+            super::int_pair_in_range(i64::MIN, i64::MAX)
+                .candidates((-3, 3))
+                .take(2),
+            vec![(0, 3), (-3, 0)],
+            "shrinks the integers like zipped shrinkers only",
+        );
+    }
+
     /// Providing failing examples out of range is logically wrong and
     /// will panic. Assumptions and graceful degradation can be made but
     /// not sure it is the logically right thing to do. Better to be
@@ -1,7 +1,13 @@
 //! Shrinkers for vectors.
 //!
 //! One design choice of the vector shrinker implementation is to aggressively
-//! try to shrink size before individual elements are shrunk.
+//! try to shrink size before individual elements are shrunk. Last, later
+//! elements are moved to earlier places and pairs of elements are shrunk
+//! together.
+//!
+//! For element types that are `PartialOrd`, the [normalizing] shrinker also
+//! makes elements more alike and ordered, by replacing elements with copies of
+//! earlier elements and by swapping adjacent elements toward sorted order.
 //!
 //! ```rust
 //! use monkey_test::*;
@@ -41,15 +47,15 @@
 //!         vec![ 1, 2, 3, 4, 5, 6, 7, 8],
 //!         vec![ 0, 2, 3, 4, 5, 6, 7, 8],
 //!         //       ↓ Second element
+//!         vec![-1, 0, 3, 4, 5, 6, 7, 8],
 //!         vec![-1, -1, 3, 4, 5, 6, 7, 8],
 //!         vec![-1, 1, 3, 4, 5, 6, 7, 8],
-//!         vec![-1, 0, 3, 4, 5, 6, 7, 8],
 //!         //          ↓ Third element
+//!         vec![-1, 2, 0, 4, 5, 6, 7, 8],
 //!         vec![-1, 2, -2, 4, 5, 6, 7, 8],
 //!         vec![-1, 2, 2, 4, 5, 6, 7, 8],
 //!         vec![-1, 2, -1, 4, 5, 6, 7, 8],
 //!         vec![-1, 2, 1, 4, 5, 6, 7, 8],
-//!         vec![-1, 2, 0, 4, 5, 6, 7, 8],
 //!         // And further ...
 //!     ]
 //! };
@@ -57,6 +63,13 @@
 
 use crate::BoxIter;
 use crate::BoxShrink;
+use num_traits::WrappingAdd;
+use num_traits::WrappingSub;
+
+/// Maximum number of pairs of elements scanned, and of candidates produced,
+/// by passes considering pairs of elements, since the number of pairs grows
+/// quadratically with the vector length.
+const MAX_PAIR_CANDIDATES: usize = 1000;
 
 /// Default vector shrinker.
 pub fn default<E: Clone + 'static>(
@@ -64,7 +77,74 @@ pub fn default<E: Clone + 'static>(
 ) -> BoxShrink<Vec<E>> {
     crate::shrinks::from_fn(move |original: Vec<E>| {
         eager_size(original.clone())
-            .chain(per_element(original, element_shrinker.clone()))
+            .chain(per_element(original.clone(), element_shrinker.clone()))
+            .chain(move_earlier(original.clone()))
+            .chain(per_pair(original, element_shrinker.clone()))
+    })
+}
+
+/// Vector shrinker like [default], that also makes elements more alike and
+/// ordered, by replacing elements with copies of earlier elements and by
+/// swapping adjacent elements toward sorted order. This removes needless
+/// variety from minimal failures, like `[1, 0]` instead of `[0, 1]`.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// let shrinker = shrinks::vec::normalizing(shrinks::int_to_zero());
+///
+/// assert!(shrinker.candidates(vec![7, 3]).any(|v| v == vec![3, 7]));
+/// ```
+pub fn normalizing<E: Clone + PartialOrd + 'static>(
+    element_shrinker: BoxShrink<E>,
+) -> BoxShrink<Vec<E>> {
+    crate::shrinks::from_fn(move |original: Vec<E>| {
+        eager_size(original.clone())
+            .chain(per_element(original.clone(), element_shrinker.clone()))
+            .chain(copy_earlier(original.clone()))
+            .chain(sort_adjacent(original.clone()))
+            .chain(move_earlier(original.clone()))
+            .chain(per_pair(original, element_shrinker.clone()))
+    })
+}
+
+/// Vector shrinker like [default], that also moves amounts between pairs of
+/// integer elements, keeping the wrapping sum of the elements. This helps
+/// failures depending on the sum of the elements, where shrinking any element
+/// alone changes the sum. Amounts are moved before elements are shrunk one by
+/// one, and only to later elements, by shrinking the earlier element.
+///
+/// ```rust
+/// use monkey_test::*;
+///
+/// let shrinker = shrinks::vec::sum_preserving(shrinks::int_to_zero());
+///
+/// assert!(shrinker.candidates(vec![7, 3]).any(|v| v == vec![0, 10]));
+/// ```
+pub fn sum_preserving<E>(element_shrinker: BoxShrink<E>) -> BoxShrink<Vec<E>>
+where
+    E: Clone + WrappingAdd + WrappingSub + 'static,
+{
+    crate::shrinks::from_fn(move |original: Vec<E>| {
+        eager_size_keeping_sum(original.clone())
+            .chain(eager_size(original.clone()))
+            .chain(move_amount(original.clone(), element_shrinker.clone()))
+            .chain(per_element(original.clone(), element_shrinker.clone()))
+            .chain(move_earlier(original.clone()))
+            .chain(per_pair(original, element_shrinker.clone()))
+    })
+}
+
+/// Vector shrinker that never moves or copies elements, only removes elements
+/// and shrinks elements, for vectors where the meaning of an element depends
+/// on its position, like a sequence of choices.
+pub(crate) fn positional<E: Clone + 'static>(
+    element_shrinker: BoxShrink<E>,
+) -> BoxShrink<Vec<E>> {
+    crate::shrinks::from_fn(move |original: Vec<E>| {
+        eager_size(original.clone())
+            .chain(per_element(original.clone(), element_shrinker.clone()))
+            .chain(per_pair(original, element_shrinker.clone()))
     })
 }
 
@@ -132,6 +212,45 @@ where
     }
 }
 
+/// Eager vector element removal, like [eager_size], where the wrapping sum of
+/// the removed elements is added to the element after them, or to the last
+/// element if there is none, keeping the sum of the vector.
+fn eager_size_keeping_sum<E>(original: Vec<E>) -> impl Iterator<Item = Vec<E>>
+where
+    E: Clone + WrappingAdd,
+{
+    let len = original.len();
+    let mut len_to_remove = len.saturating_sub(1);
+    let mut start_of_remove = 0;
+
+    std::iter::from_fn(move || {
+        if start_of_remove + len_to_remove > len {
+            start_of_remove = 0;
+            len_to_remove = if len_to_remove == 1 {
+                0
+            } else {
+                len_to_remove.div_ceil(2)
+            };
+        }
+        if len_to_remove == 0 {
+            return None;
+        }
+
+        let end_of_remove = start_of_remove + len_to_remove;
+        let mut candidate = original.clone();
+        let removed = candidate
+            .drain(start_of_remove..end_of_remove)
+            .reduce(|sum, e| sum.wrapping_add(&e));
+        let receiver = start_of_remove.min(candidate.len() - 1);
+        if let Some(removed) = removed {
+            candidate[receiver] = candidate[receiver].wrapping_add(&removed);
+        }
+        start_of_remove = end_of_remove;
+
+        Some(candidate)
+    })
+}
+
 /// Per element shrink iterator
 fn per_element<E>(
     original: Vec<E>,
@@ -164,6 +283,133 @@ where
         })
 }
 
+/// Candidates where an element is replaced with a copy of an earlier,
+/// different, element. Only the first pairs of elements are tried, see
+/// [MAX_PAIR_CANDIDATES].
+fn copy_earlier<E>(original: Vec<E>) -> impl Iterator<Item = Vec<E>>
+where
+    E: Clone + PartialEq,
+{
+    let len = original.len();
+
+    (1..len)
+        .flat_map(move |to| (0..to).map(move |from| (from, to)))
+        .take(MAX_PAIR_CANDIDATES)
+        .filter_map(move |(from, to)| {
+            if original[from] == original[to] {
+                None
+            } else {
+                let mut candidate = original.clone();
+                candidate[to] = original[from].clone();
+                Some(candidate)
+            }
+        })
+}
+
+/// Candidates where an element is replaced by a later element, that is moved
+/// from its own place. This shortens vectors where removing any element alone
+/// makes the failure go away, like when the meaning of an element depends on
+/// the vector length. Only the first pairs of elements are tried, see
+/// [MAX_PAIR_CANDIDATES].
+fn move_earlier<E>(original: Vec<E>) -> impl Iterator<Item = Vec<E>>
+where
+    E: Clone,
+{
+    let len = original.len();
+
+    (1..len)
+        .flat_map(move |from| (0..from).map(move |to| (from, to)))
+        .take(MAX_PAIR_CANDIDATES)
+        .map(move |(from, to)| {
+            let mut candidate = original.clone();
+            candidate[to] = candidate.remove(from);
+            candidate
+        })
+}
+
+/// Candidates where two adjacent elements, not in sorted order, are swapped.
+fn sort_adjacent<E>(original: Vec<E>) -> impl Iterator<Item = Vec<E>>
+where
+    E: Clone + PartialOrd,
+{
+    let len = original.len();
+
+    (1..len).filter_map(move |index| {
+        if original[index] < original[index - 1] {
+            let mut candidate = original.clone();
+            candidate.swap(index - 1, index);
+            Some(candidate)
+        } else {
+            None
+        }
+    })
+}
+
+/// Candidates where an element is shrunk and the amount it shrunk by is added
+/// to a later element. Only the first pairs of elements are tried and the
+/// number of candidates is limited, see [MAX_PAIR_CANDIDATES].
+fn move_amount<E>(
+    original: Vec<E>,
+    elem_shrinker: BoxShrink<E>,
+) -> impl Iterator<Item = Vec<E>>
+where
+    E: Clone + WrappingAdd + WrappingSub + 'static,
+{
+    let len = original.len();
+    let original = std::rc::Rc::new(original);
+
+    (1..len)
+        .flat_map(move |to| (0..to).map(move |from| (from, to)))
+        .take(MAX_PAIR_CANDIDATES)
+        .flat_map(move |(from, to)| {
+            let original = original.clone();
+
+            elem_shrinker
+                .candidates(original[from].clone())
+                .take(100)
+                .map(move |shrunk| {
+                    let amount = original[from].wrapping_sub(&shrunk);
+                    let mut candidate = original.as_ref().clone();
+                    candidate[from] = shrunk;
+                    candidate[to] = original[to].wrapping_add(&amount);
+                    candidate
+                })
+        })
+        .take(MAX_PAIR_CANDIDATES)
+}
+
+/// Candidates where two elements are shrunk together, by combining the
+/// candidates of both elements in lockstep. Only the first pairs of elements
+/// are tried and the number of candidates is limited, see
+/// [MAX_PAIR_CANDIDATES].
+fn per_pair<E>(
+    original: Vec<E>,
+    elem_shrinker: BoxShrink<E>,
+) -> impl Iterator<Item = Vec<E>>
+where
+    E: Clone + 'static,
+{
+    let len = original.len();
+    let original = std::rc::Rc::new(original);
+
+    (1..len)
+        .flat_map(move |second| (0..second).map(move |first| (first, second)))
+        .take(MAX_PAIR_CANDIDATES)
+        .flat_map(move |(first, second)| {
+            let firsts = elem_shrinker.candidates(original[first].clone());
+            let seconds = elem_shrinker.candidates(original[second].clone());
+            let original = original.clone();
+
+            firsts.zip(seconds).take(100).map(move |(c0, c1)| {
+                let mut candidate = original.as_ref().clone();
+                candidate[first] = c0;
+                candidate[second] = c1;
+                candidate
+            })
+        })
+        .take(MAX_PAIR_CANDIDATES)
+}
+
 #[cfg(test)]
 mod test {
     use crate::testing::assert_iter_eq;
@@ -282,4 +528,158 @@ mod test {
             this case using zero",
         )
     }
+
+    #[test]
+    fn copy_earlier_replaces_elements_with_earlier_different_elements() {
+        assert_iter_eq(
+            super::copy_earlier(vec![1, 2, 1]),
+            vec![
+                // 2nd element replaced with 1st
+                vec![1, 1, 1],
+                // 3rd element replaced with 2nd, but not with the equal 1st
+                vec![1, 2, 2],
+            ],
+            "copying earlier elements, skipping equal elements",
+        )
+    }
+
+    #[test]
+    fn move_earlier_replaces_elements_with_later_elements() {
+        assert_iter_eq(
+            super::move_earlier(vec![1, 2, 3]),
+            vec![
+                // 2nd element moved to 1st
+                vec![2, 3],
+                // 3rd element moved to 1st
+                vec![3, 2],
+                // 3rd element moved to 2nd
+                vec![1, 3],
+            ],
+            "later elements replace earlier elements, shortening the vector",
+        )
+    }
+
+    #[test]
+    fn eager_keeping_sum_adds_removed_elements_to_element_after() {
+        assert_iter_eq(
+            super::eager_size_keeping_sum(vec![1, 2, 3, 4]),
+            vec![
+                // all but the last element removed
+                vec![10],
+                // 1st half removed
+                vec![6, 4],
+                // 2nd half removed, added to the last remaining element
+                vec![1, 9],
+                // single elements removed
+                vec![3, 3, 4],
+                vec![1, 5, 4],
+                vec![1, 2, 7],
+                vec![1, 2, 7],
+            ],
+            "removes iteratively smaller parts, keeping the sum",
+        )
+    }
+
+    #[test]
+    fn eager_keeping_sum_wraps_around() {
+        assert_iter_eq(
+            super::eager_size_keeping_sum(vec![i8::MAX, 1]),
+            vec![vec![i8::MIN], vec![i8::MIN]],
+            "keeps the wrapping sum",
+        )
+    }
+
+    #[test]
+    fn move_amount_adds_shrunk_amount_to_later_element() {
+        assert_iter_eq(
+            super::move_amount(
+                vec![5, 6, 7],
+                crate::shrinks::fixed::sequence(&[0, 1]),
+            ),
+            vec![
+                // moving from 1st to 2nd element
+                vec![0, 11, 7],
+                vec![1, 10, 7],
+                // moving from 1st to 3rd element
+                vec![0, 6, 12],
+                vec![1, 6, 11],
+                // moving from 2nd to 3rd element
+                vec![5, 0, 13],
+                vec![5, 1, 12],
+            ],
+            "every pair of elements, keeping the sum",
+        )
+    }
+
+    #[test]
+    fn sort_adjacent_swaps_unsorted_neighbours() {
+        assert_iter_eq(
+            super::sort_adjacent(vec![3, 1, 2, 2, 0]),
+            vec![vec![1, 3, 2, 2, 0], vec![3, 1, 2, 0, 2]],
+            "only unsorted adjacent elements are swapped",
+        )
+    }
+
+    #[test]
+    fn pair_passes_are_limited_for_long_vectors() {
+        let long_vec = (0..1000).collect::<Vec<i32>>();
+
+        assert_eq!(
+            super::copy_earlier(long_vec.clone()).count(),
+            super::MAX_PAIR_CANDIDATES
+        );
+        assert_eq!(
+            super::move_earlier(long_vec.clone()).count(),
+            super::MAX_PAIR_CANDIDATES
+        );
+        assert_eq!(
+            super::move_amount(long_vec.clone(), crate::shrinks::int_to_zero())
+                .count(),
+            super::MAX_PAIR_CANDIDATES
+        );
+        assert_eq!(
+            super::per_pair(long_vec, crate::shrinks::int_to_zero()).count(),
+            super::MAX_PAIR_CANDIDATES
+        );
+    }
+
+    #[test]
+    fn per_pair_scans_limited_number_of_pairs() {
+        let pairs_scanned = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = pairs_scanned.clone();
+        let counting_shrinker = crate::shrinks::from_fn(move |_: u32| {
+            counter.set(counter.get() + 1);
+            std::iter::empty()
+        });
+
+        assert_eq!(
+            super::per_pair(vec![0; 1000], counting_shrinker).count(),
+            0
+        );
+        // The element shrinker is asked for candidates of both elements in
+        // each scanned pair.
+        assert_eq!(pairs_scanned.get(), 2 * super::MAX_PAIR_CANDIDATES);
+    }
+
+    #[test]
+    fn per_pair_shrinks_two_elements_in_lockstep() {
+        assert_iter_eq(
+            super::per_pair(
+                vec![5, 6, 7],
+                crate::shrinks::fixed::sequence(&[0, 1]),
+            ),
+            vec![
+                // shrinking 1st and 2nd element
+                vec![0, 0, 7],
+                vec![1, 1, 7],
+                // shrinking 1st and 3rd element
+                vec![0, 6, 0],
+                vec![1, 6, 1],
+                // shrinking 2nd and 3rd element
+                vec![5, 0, 0],
+                vec![5, 1, 1],
+            ],
+            "per pair shrinker shrinks every pair of elements together",
+        )
+    }
 }
@@ -1,54 +1,199 @@
+use crate::BoxIter;
 use crate::BoxShrink;
+use std::rc::Rc;
 
-/// Combine two shrinkers together element wise into shrinker of tuples.
-///
-/// ```rust
-/// use monkey_test::*;
-///
-/// let alfa1: BoxShrink<u8> = shrinks::int_to_zero::<u8>();
-/// let beta1: BoxShrink<i64> = shrinks::int_to_zero::<i64>();
-///
-/// let alfa2: BoxShrink<u8> = shrinks::int_to_zero::<u8>();
-/// let beta2: BoxShrink<i64> = shrinks::int_to_zero::<i64>();
-///
-/// // Zip two shrinkers to a tuple shrinker.
-/// let tuples1: BoxShrink<(u8, i64)> = shrinks::zip(alfa1, beta1);
-///
-/// // Shorthand way to do the same thing.
-/// let tuples2: BoxShrink<(u8, i64)> = alfa2.zip(beta2);
-/// ```
-pub fn zip<E0, E1>(
-    shrink0: BoxShrink<E0>,
-    shrink1: BoxShrink<E1>,
-) -> BoxShrink<(E0, E1)>
+/// Interleave iterators fairly, one item from each iterator in turn, skipping
+/// iterators that have ended.
+pub(crate) fn interleave<T: 'static>(iterators: Vec<BoxIter<T>>) -> BoxIter<T> {
+    let mut iterators = iterators;
+    let mut index = 0;
+
+    Box::new(std::iter::from_fn(move || {
+        while !iterators.is_empty() {
+            index %= iterators.len();
+            match iterators[index].next() {
+                Some(item) => {
+                    index += 1;
+                    return Some(item);
+                }
+                None => {
+                    let _ = iterators.remove(index);
+                }
+            }
+        }
+        None
+    }))
+}
+
+/// Candidates of one part of a tuple, as whole tuples.
+type PartCandidates<T> = Rc<dyn Fn() -> BoxIter<T>>;
+
+/// Copies one part of a tuple from another tuple.
+type PartCopy<T> = Rc<dyn Fn(&mut T, &T)>;
+
+/// One part of the tuple `_original`, given as argument only to infer the
+/// tuple type.
+fn part<T, C, F>(
+    _original: &T,
+    candidates: C,
+    copy: F,
+) -> (PartCandidates<T>, PartCopy<T>)
 where
-    E0: Clone + 'static,
-    E1: Clone + 'static,
+    C: Fn() -> BoxIter<T> + 'static,
+    F: Fn(&mut T, &T) + 'static,
 {
-    crate::shrinks::from_fn(move |original: (E0, E1)| {
-        let o0 = original.0.clone();
-        let o1 = original.1.clone();
+    (Rc::new(candidates), Rc::new(copy))
+}
+
+/// Candidates where two parts are shrunk together, by combining the
+/// candidates of both parts in lockstep. For tuples of two parts, this is
+/// the same as shrinking all parts together, so then there are no
+/// candidates.
+fn pairs_in_lockstep<T: 'static>(
+    parts: Vec<(PartCandidates<T>, PartCopy<T>)>,
+) -> BoxIter<T> {
+    if parts.len() <= 2 {
+        return Box::new(std::iter::empty());
+    }
+
+    let parts = Rc::new(parts);
+
+    Box::new(
+        (1..parts.len())
+            .flat_map(|second| (0..second).map(move |first| (first, second)))
+            .flat_map(move |(first, second)| {
+                let (firsts, _) = &parts[first];
+                let (seconds, copy_second) = &parts[second];
+                let copy_second = copy_second.clone();
+
+                firsts().zip(seconds()).take(100).map(
+                    move |(mut candidate, from)| {
+                        copy_second(&mut candidate, &from);
+                        candidate
+                    },
+                )
+            }),
+    )
+}
+
+macro_rules! zip_n {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $(($index:tt, $E:ident, $shrink:ident)),+
+    ) => {
+        $(#[$meta])*
+        pub fn $name<$($E),+>(
+            $($shrink: BoxShrink<$E>),+
+        ) -> BoxShrink<($($E,)+)>
+        where
+            $($E: Clone + 'static),+
+        {
+            crate::shrinks::from_fn(move |original: ($($E,)+)| {
+                let parts = vec![$({
+                    let shrink = $shrink.clone();
+                    let from = original.clone();
+                    part(
+                        &original,
+                        move || {
+                            let from = from.clone();
+                            Box::new(
+                                shrink
+                                    .candidates(from.$index.clone())
+                                    .map(move |candidate| {
+                                        let mut tuple = from.clone();
+                                        tuple.$index = candidate;
+                                        tuple
+                                    }),
+                            )
+                        },
+                        |tuple, from| tuple.$index = from.$index.clone(),
+                    )
+                }),+];
+
+                let one_part_at_a_time = interleave(
+                    parts.iter().map(|(candidates, _)| candidates()).collect(),
+                );
+
+                let pairs_of_parts = pairs_in_lockstep(parts);
+
+                let mut parts = ($($shrink.candidates(original.$index),)+);
+                let all_parts_together =
+                    std::iter::from_fn(move || Some(($(parts.$index.next()?,)+)));
+
+                one_part_at_a_time
+                    .chain(pairs_of_parts)
+                    .chain(all_parts_together)
+            })
+        }
+    };
+}
+
+zip_n! {
+    /// Combine two shrinkers together element wise into shrinker of tuples.
+    ///
+    /// The candidates of the tuple parts are interleaved fairly, taking one
+    /// candidate from each part in turn, so that no part needs to be exhausted
+    /// before the others are tried. Since shrinking restarts from every
+    /// accepted candidate, all parts keep being shrunk after a success on any
+    /// part. Then, for tuples of more than two parts, pairs of parts are
+    /// shrunk together in lockstep. Last, all parts are shrunk together in
+    /// lockstep, for failures where the parts are coupled to each other.
+    ///
+    /// For tuples with more parts, see [zip_3], [zip_4], [zip_5] and [zip_6].
+    ///
+    /// ```rust
+    /// use monkey_test::*;
+    ///
+    /// let alfa1: BoxShrink<u8> = shrinks::int_to_zero::<u8>();
+    /// let beta1: BoxShrink<i64> = shrinks::int_to_zero::<i64>();
+    ///
+    /// let alfa2: BoxShrink<u8> = shrinks::int_to_zero::<u8>();
+    /// let beta2: BoxShrink<i64> = shrinks::int_to_zero::<i64>();
+    ///
+    /// // Zip two shrinkers to a tuple shrinker.
+    /// let tuples1: BoxShrink<(u8, i64)> = shrinks::zip(alfa1, beta1);
+    ///
+    /// // Shorthand way to do the same thing.
+    /// let tuples2: BoxShrink<(u8, i64)> = alfa2.zip(beta2);
+    /// ```
+    zip, (0, E0, shrink0), (1, E1, shrink1)
+}
 
-        let it_left = shrink0
-            .candidates(original.0.clone())
-            .map(move |item0| (item0, o1.clone()));
+zip_n! {
+    /// Combine 3 shrinkers together element wise into shrinker of tuples.
+    /// See [zip].
+    zip_3, (0, E0, shrink0), (1, E1, shrink1), (2, E2, shrink2)
+}
 
-        let it_right = shrink1
-            .candidates(original.1.clone())
-            .map(move |item1| (o0.clone(), item1));
+zip_n! {
+    /// Combine 4 shrinkers together element wise into shrinker of tuples.
+    /// See [zip].
+    zip_4,
+    (0, E0, shrink0), (1, E1, shrink1), (2, E2, shrink2), (3, E3, shrink3)
+}
 
-        let it_both = shrink0
-            .candidates(original.0.clone())
-            .zip(shrink1.candidates(original.1.clone()));
+zip_n! {
+    /// Combine 5 shrinkers together element wise into shrinker of tuples.
+    /// See [zip].
+    zip_5,
+    (0, E0, shrink0), (1, E1, shrink1), (2, E2, shrink2), (3, E3, shrink3),
+    (4, E4, shrink4)
+}
 
-        it_left.chain(it_right).chain(it_both)
-    })
+zip_n! {
+    /// Combine 6 shrinkers together element wise into shrinker of tuples.
+    /// See [zip].
+    zip_6,
+    (0, E0, shrink0), (1, E1, shrink1), (2, E2, shrink2), (3, E3, shrink3),
+    (4, E4, shrink4), (5, E5, shrink5)
 }
 
 #[cfg(test)]
 mod test {
+    use crate::shrinks::fixed;
     use crate::shrinks::int_to_zero;
     use crate::shrinks::none;
+    use crate::testing::assert_iter_eq;
     use crate::testing::assert_shrinker_has_at_least_these_candidates;
     use crate::BoxShrink;
 
@@ -62,8 +207,6 @@ mod test {
         assert_eq!(actual_length, 0)
     }
 
-    /// The combination of candidates are not complete, but is good enough as
-    /// initial behaviour.
     #[test]
     fn returns_permutations_of_inner_candidates() {
         let shrink: BoxShrink<(u8, u8)> =
@@ -88,4 +231,53 @@ mod test {
             ],
         );
     }
+
+    #[test]
+    fn interleaves_candidates_of_parts_fairly() {
+        let shrink: BoxShrink<(u8, u8, u8)> = super::zip_3(
+            fixed::sequence(&[1, 2, 3]),
+            fixed::sequence(&[4]),
+            fixed::sequence(&[5, 6]),
+        );
+
+        assert_iter_eq(
+            shrink.candidates((10, 20, 30)),
+            vec![
+                // One part at a time, in turn
+                (1, 20, 30),
+                (10, 4, 30),
+                (10, 20, 5),
+                (2, 20, 30),
+                (10, 20, 6),
+                (3, 20, 30),
+                // Pairs of parts together
+                (1, 4, 30),
+                (1, 20, 5),
+                (2, 20, 6),
+                (10, 4, 5),
+                // All parts together
+                (1, 4, 5),
+            ],
+            "candidates of each part in turn, skipping ended parts, then \
+            pairs of parts together and last all parts together",
+        );
+    }
+
+    #[test]
+    fn supports_six_parts() {
+        let shrink = super::zip_6(
+            int_to_zero::<u8>(),
+            int_to_zero::<u8>(),
+            int_to_zero::<u8>(),
+            int_to_zero::<u8>(),
+            int_to_zero::<u8>(),
+            int_to_zero::<u8>(),
+        );
+
+        assert_shrinker_has_at_least_these_candidates(
+            shrink,
+            (1, 1, 1, 1, 1, 1),
+            &[(0, 1, 1, 1, 1, 1), (1, 1, 1, 1, 1, 0), (0, 0, 0, 0, 0, 0)],
+        );
+    }
 }
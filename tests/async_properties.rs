@@ -0,0 +1,72 @@
+//! These tests shows how to test properties of async code, without wrapping
+//! each property body in a hand-rolled `block_on`.
+
+use monkey_test::*;
+use std::cell::Cell;
+use std::sync::Arc;
+use std::task::Wake;
+
+async fn double(n: u32) -> u32 {
+    n * 2
+}
+
+async fn is_small(n: u8) -> bool {
+    n < 100
+}
+
+#[test]
+fn async_property_runs_on_built_in_executor() {
+    monkey_test()
+        .with_generator(gens::u32::ranged(..1000))
+        .assert_true_async(|n| async move { double(n).await == n + n });
+}
+
+#[test]
+fn async_property_failure_is_shrunk() {
+    monkey_test()
+        .with_generator(gens::u8::any())
+        .test_async(is_small)
+        .assert_minimum_failure(100);
+}
+
+#[test]
+#[should_panic(expected = "Reason: Expecting no panic, but got panic \"boom\"")]
+fn async_property_panic_is_a_failure() {
+    monkey_test()
+        .with_generator(gens::u8::any())
+        .assert_true_async(|_| async move { panic!("boom") });
+}
+
+thread_local! {
+    static BLOCK_ON_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Waker doing nothing, since futures in these tests are always ready.
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Stand-in for the `block_on` of some async runtime, counting its calls.
+fn counting_block_on(future: BoxFuture<()>) {
+    BLOCK_ON_CALLS.with(|calls| calls.set(calls.get() + 1));
+
+    // Any async runtime can be used here. For simplicity, this test polls the
+    // future once, which is enough for futures that are immediately ready.
+    let waker = Arc::new(NoopWaker).into();
+    let mut context = std::task::Context::from_waker(&waker);
+    let mut future = future;
+    assert!(future.as_mut().poll(&mut context).is_ready());
+}
+
+#[test]
+fn async_property_can_run_on_user_supplied_block_on() {
+    monkey_test()
+        .with_example_count(50)
+        .with_block_on(counting_block_on)
+        .with_generator(gens::u8::any())
+        .assert_true_async(|n| async move { n as u16 + 1 > n as u16 });
+
+    assert_eq!(BLOCK_ON_CALLS.with(|calls| calls.get()), 50);
+}
@@ -27,6 +27,8 @@ fn can_fail_with_details_when_using_check() {
             seed: 123456,
             title: Some("Less than thirteen".into()),
             reason: "Expecting 'true' but got 'false'.".into(),
+            flaky: false,
+            explanation: String::new(),
         }
     );
 }
@@ -85,6 +87,48 @@ fn can_assert_eq() {
         .assert_eq(|n| n, |n| n / 2 * 2);
 }
 
+/// When failing values are too large to be printed on a single line, the
+/// failure reason is extended with the paths of differing parts and a diff of
+/// the pretty-printed values. The first line of the reason stays the same.
+#[test]
+#[should_panic(expected = "Reason: Actual value should equal expected \
+    [0, 1], but got [0, 0].\n\
+    \n\
+    Differing paths: [1]\n\
+    Diff (- expected, + actual):\n  [\n      0,\n-     1,\n+     0,\n  ]")]
+fn can_assert_eq_with_diff_of_large_values() {
+    monkey_test()
+        .with_generator(gens::u8::ranged(..10))
+        .assert_eq(|n| vec![0, n], |_| vec![0, 0]);
+}
+
+/// The diff of large values is not part of the reason in the result of
+/// test_eq, but given as explanation of the reason.
+#[test]
+fn test_eq_explains_reason_with_diff_of_large_values() {
+    let result = monkey_test()
+        .with_generator(gens::u8::ranged(..10))
+        .test_eq(|n| vec![0, n], |_| vec![0, 0]);
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            reason,
+            explanation,
+            ..
+        } => {
+            assert_eq!(
+                reason,
+                "Actual value should equal expected [0, 1], but got [0, 0]."
+            );
+            assert!(
+                explanation.starts_with("\n\nDiffering paths: [1]\n"),
+                "unexpected explanation {explanation:?}"
+            );
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
+
 /// Shows how to test for the proper of values being not equal, here shown as a
 /// faulty property due to positive odd numbers divided by two are rounded down.
 ///
@@ -100,6 +144,131 @@ fn can_assert_ne() {
         .assert_ne(|n| (n + 2) / 2, |n| (n + 3) / 2);
 }
 
+/// Properties returning a `Result` can be checked to always be `Ok`. The
+/// error is presented as the failure reason.
+#[test]
+#[should_panic(expected = "Monkey test property failed!\n\
+    Failure: -1\n\
+    Reason: Expecting 'Ok' but got 'Err(TryFromIntError(()))'.")]
+fn can_assert_ok() {
+    monkey_test()
+        .with_generator(gens::i16::any())
+        .assert_ok(u8::try_from);
+}
+
+#[test]
+fn can_test_err() {
+    monkey_test()
+        .with_generator(gens::i16::ranged(-300..300))
+        .test_err(u8::try_from)
+        .assert_minimum_failure(0);
+}
+
+#[test]
+fn can_assert_panics() {
+    monkey_test()
+        .with_generator(gens::u8::any())
+        .assert_panics(|n| {
+            let _ = vec![0u8; 5][n as usize + 5];
+        })
+        .assert_panics_with_message(|n| panic!("bad number {n}"), "bad number");
+}
+
+#[test]
+fn can_test_panics_with_message() {
+    monkey_test()
+        .with_generator(gens::u8::any())
+        .test_panics_with_message(
+            |n| {
+                if n >= 10 {
+                    panic!("too large");
+                }
+            },
+            "too large",
+        )
+        .assert_minimum_failure(0);
+}
+
+#[test]
+#[should_panic(expected = "Reason: Expecting panic with message containing \
+    \"overflow\", but got panic \"other\" in file")]
+fn can_assert_panics_with_message() {
+    monkey_test()
+        .with_generator(gens::u8::any())
+        .assert_panics_with_message(|_| panic!("other"), "overflow");
+}
+
+#[test]
+#[should_panic(expected = "Monkey test property failed!\n\
+    Failure: 0\n\
+    Reason: Value 0 should be less than 0.")]
+fn can_assert_lt() {
+    monkey_test()
+        .with_generator(gens::u32::ranged(..3))
+        .assert_le(|n| n, |n| n * 2)
+        .assert_lt(|n| n, |n| n * 2);
+}
+
+#[test]
+fn can_test_le() {
+    monkey_test()
+        .with_generator(gens::i32::ranged(-1000..1000))
+        .test_le(|n| n * 2, |n| n)
+        .assert_minimum_failure(1);
+}
+
+#[test]
+#[should_panic(expected = "Reason: Actual value should approximately equal \
+    expected 0.0 within tolerance 1e-9, but got 0.5.")]
+fn can_assert_approx_eq() {
+    monkey_test()
+        .with_generator(gens::f64::ranged(0.0..1000.0))
+        .assert_approx_eq(|x| x, |x| (x * x).sqrt(), 1e-9)
+        .assert_approx_eq(|x| x.abs(), |x| x.abs() + 0.5, 1e-9);
+}
+
+/// All asserts have a `test_*` counterpart, returning the result instead of
+/// panicking, for inspecting the minimum failure.
+#[test]
+fn can_test_instead_of_assert() {
+    let conf = monkey_test().with_generator(gens::u32::ranged(10..1000));
+
+    conf.test_no_panic(|n| assert!(n < 500))
+        .assert_minimum_failure(500);
+
+    conf.test_eq(|n| n, |n| n / 2 * 2)
+        .assert_minimum_failure(11);
+
+    conf.test_ne(|n| n / 20, |n| n / 21)
+        .assert_minimum_failure(10);
+}
+
+/// The most general form of property returns `Ok(())` or the failure reason.
+#[test]
+fn can_test_result() {
+    let result = monkey_test()
+        .with_generator(gens::u32::ranged(..1000))
+        .test_result(|n| {
+            if n % 7 != 6 {
+                Ok(())
+            } else {
+                Err(format!("{n} ends with six in base seven"))
+            }
+        });
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            reason,
+            ..
+        } => {
+            assert_eq!(minimum_failure, 6);
+            assert_eq!(reason, "6 ends with six in base seven");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
+
 #[test]
 fn use_all_settings_available() {
     monkey_test()
@@ -156,3 +325,15 @@ fn filtering_of_generated_values() {
         .test_true(|_example| false)
         .assert_minimum_failure(101);
 }
+
+/// Failing examples can be presented with other formatting than the default
+/// `{:?}` debug format, here as a hex dump of the bytes.
+#[test]
+#[should_panic(expected = "Monkey test property failed!\n\
+    Failure: [2 bytes]\n00000000  00 00  |..|\n")]
+fn can_format_failing_examples() {
+    monkey_test()
+        .with_generator(gens::vec::any(gens::u8::any()))
+        .with_formatter(formatters::hex())
+        .assert_true(|bytes| bytes.len() < 2);
+}
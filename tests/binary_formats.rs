@@ -0,0 +1,60 @@
+//! These tests shows how to generate byte vectors, for testing decoders of
+//! binary formats.
+
+use monkey_test::gens::bytes::LengthPrefix;
+use monkey_test::*;
+
+/// Decoder of frames with a one byte length prefix, failing on frames with
+/// a payload of three bytes or more.
+fn buggy_decode(frame: &[u8]) -> Result<&[u8], String> {
+    let (len, payload) = frame.split_first().ok_or("missing length")?;
+    if *len >= 3 {
+        return Err("too long payload".to_string());
+    }
+    payload
+        .get(..*len as usize)
+        .ok_or_else(|| "truncated payload".to_string())
+}
+
+#[test]
+fn shrinks_length_prefixed_frame_keeping_prefix_correct() {
+    monkey_test()
+        .with_generator(gens::bytes::length_prefixed(
+            LengthPrefix::U8,
+            gens::bytes::any(),
+        ))
+        .test_true(|frame| buggy_decode(&frame).is_ok())
+        .assert_minimum_failure(vec![3, 0, 0, 0]);
+}
+
+#[test]
+fn utf8_bytes_are_valid() {
+    monkey_test()
+        .with_generator(gens::bytes::utf8())
+        .assert_true(|bytes| String::from_utf8(bytes).is_ok());
+}
+
+#[test]
+fn shrinks_invalid_utf8_to_smallest_invalid_byte() {
+    monkey_test()
+        .with_generator(gens::bytes::utf8_invalid())
+        .test_true(|bytes| String::from_utf8(bytes).is_ok())
+        .assert_minimum_failure(vec![0x80]);
+}
+
+#[test]
+fn shrinks_spliced_magic_value_by_removing_other_bytes() {
+    monkey_test()
+        .with_generator(gens::bytes::with_magic(gens::bytes::mostly_ascii()))
+        .test_true(|bytes| !bytes.contains(&0xFF))
+        .assert_minimum_failure(vec![0xFF]);
+}
+
+#[test]
+fn mostly_ascii_bytes_are_mostly_ascii() {
+    monkey_test()
+        .with_generator(gens::bytes::mostly_ascii())
+        .assert_true(|bytes| {
+            bytes.iter().filter(|b| b.is_ascii()).count() * 2 >= bytes.len()
+        });
+}
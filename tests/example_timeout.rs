@@ -0,0 +1,121 @@
+//! These tests shows how examples running for too long can be treated as
+//! failures.
+//!
+//! Timeouts are generous, so that examples not expected to time out do not
+//! time out on a loaded machine.
+
+use monkey_test::*;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Timeout used when examples are expected to time out.
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Stand-in for slow code under test, running for longer than [TIMEOUT].
+fn run_slowly() {
+    std::thread::sleep(TIMEOUT * 2);
+}
+
+#[test]
+fn fast_examples_pass_within_timeout() {
+    monkey_test()
+        .with_example_timeout(Duration::from_secs(10))
+        .with_generator(gens::u8::any())
+        .assert_true(|n| n as u16 + 1 > n as u16);
+}
+
+#[test]
+fn timed_out_example_is_shrunk_to_smallest_still_timing_out() {
+    let result = monkey_test()
+        .with_example_timeout(TIMEOUT)
+        .with_generator(gens::u8::ranged(10..=20))
+        .with_shrinker(shrinks::int_to_zero())
+        .test_true(|n| {
+            if n >= 10 {
+                run_slowly();
+            }
+            true
+        });
+
+    result.assert_minimum_failure(10);
+
+    if let MonkeyResult::MonkeyErr { reason, .. } = result {
+        assert_eq!(
+            reason,
+            format!("Expecting no timeout, but timed out after {TIMEOUT:?}.")
+        );
+    }
+}
+
+#[test]
+fn examples_need_not_be_send_with_timeout() {
+    monkey_test()
+        .with_example_timeout(Duration::from_secs(10))
+        .with_generator(gens::u8::any().map(Rc::new, |n| *n))
+        .assert_true(|n| *n == *Rc::clone(&n));
+}
+
+#[test]
+#[should_panic(expected = "Reason: Expecting no panic, but got panic \"boom\"")]
+fn panic_is_still_caught_with_timeout() {
+    monkey_test()
+        .with_example_timeout(Duration::from_secs(10))
+        .with_generator(gens::u8::any())
+        .assert_no_panic(|_| panic!("boom"));
+}
+
+#[test]
+fn expected_panic_is_still_caught_with_timeout() {
+    monkey_test()
+        .with_example_timeout(Duration::from_secs(10))
+        .with_generator(gens::u8::any())
+        .assert_panics_with_message(|n| panic!("got {n}"), "got");
+}
+
+/// Only odd examples time out, so that shrinking keeps finding failures
+/// taking one step at a time, instead of going straight for zero.
+#[test]
+fn shrinking_stops_after_too_many_timeouts() {
+    let result = monkey_test()
+        .with_seed(1337)
+        .with_example_timeout(TIMEOUT)
+        .with_generator(gens::u64::any())
+        .test_true(|n| {
+            if n % 2 == 1 {
+                run_slowly();
+            }
+            true
+        });
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            seed,
+            reason,
+            shrink_count,
+            ..
+        } => {
+            assert_eq!(seed, 1337);
+            assert_eq!(shrink_count, 19);
+            assert!(reason.ends_with(
+                "Shrinking stopped early, since 20 examples timed out."
+            ));
+        }
+        MonkeyResult::MonkeyOk() => panic!("Expecting failure"),
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "Shrinking stopped early, since 20 examples timed out"
+)]
+fn expected_panic_stops_shrinking_after_too_many_timeouts() {
+    monkey_test()
+        .with_example_timeout(TIMEOUT)
+        .with_generator(gens::u64::any())
+        .assert_panics(|n| {
+            if n % 2 == 1 {
+                run_slowly();
+            }
+            panic!("even number")
+        });
+}
@@ -0,0 +1,74 @@
+//! These tests shows how flaky properties, not only depending on the example,
+//! are detected by re-running failing examples.
+
+use monkey_test::*;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+
+/// Stand-in for code under test failing every other call for large input.
+fn fails_every_other_call(calls: &AtomicU32, n: u8) -> bool {
+    let call = calls.fetch_add(1, Ordering::SeqCst);
+    n < 10 || call % 2 == 1
+}
+
+/// Stand-in for code under test failing consistently from 10, but only
+/// every other call from 5.
+fn fails_consistently_from_ten(calls: &AtomicU32, n: u8) -> bool {
+    let call = calls.fetch_add(1, Ordering::SeqCst);
+    n < 5 || (n < 10 && call % 2 == 1)
+}
+
+fn is_flaky<E>(result: &MonkeyResult<E>) -> bool {
+    match result {
+        MonkeyResult::MonkeyOk() => panic!("Expecting failure."),
+        MonkeyResult::MonkeyErr { flaky, .. } => *flaky,
+    }
+}
+
+#[test]
+fn consistent_failure_is_not_flaky() {
+    let result = monkey_test()
+        .with_failure_reruns(5)
+        .with_generator(gens::u8::any())
+        .test_true(|n| n < 10);
+
+    result.assert_minimum_failure(10);
+    assert!(!is_flaky(&result));
+}
+
+#[test]
+fn failure_sometimes_passing_is_flaky() {
+    static CALLS: AtomicU32 = AtomicU32::new(0);
+
+    let result = monkey_test()
+        .with_failure_reruns(3)
+        .with_generator(gens::u8::ranged(10..))
+        .test_true(|n| fails_every_other_call(&CALLS, n));
+
+    assert!(is_flaky(&result));
+}
+
+#[test]
+fn shrinking_only_accepts_consistent_failures() {
+    static CALLS: AtomicU32 = AtomicU32::new(0);
+
+    let result = monkey_test()
+        .with_failure_reruns(3)
+        .with_generator(gens::u8::ranged(20..))
+        .with_shrinker(shrinks::int_to_zero())
+        .test_true(|n| fails_consistently_from_ten(&CALLS, n));
+
+    result.assert_minimum_failure(10);
+    assert!(!is_flaky(&result));
+}
+
+#[test]
+#[should_panic(expected = "Flaky: The property sometimes passed")]
+fn flaky_failure_is_presented() {
+    static CALLS: AtomicU32 = AtomicU32::new(0);
+
+    monkey_test()
+        .with_failure_reruns(3)
+        .with_generator(gens::u8::ranged(10..))
+        .assert_true(|n| fails_every_other_call(&CALLS, n));
+}
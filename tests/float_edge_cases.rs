@@ -0,0 +1,67 @@
+//! These tests shows how to generate floating point edge cases, like
+//! subnormals, NaNs with different payloads and values one ULP (unit in the
+//! last place) away from given values.
+
+use monkey_test::*;
+use std::num::FpCategory;
+
+#[test]
+fn subnormals_are_subnormal() {
+    monkey_test()
+        .with_generator(gens::f64::subnormal())
+        .assert_true(|f| f.classify() == FpCategory::Subnormal);
+}
+
+#[test]
+fn shrinks_subnormal_toward_smallest_subnormal() {
+    monkey_test()
+        .with_generator(gens::f32::subnormal())
+        .test_true(|f| f < 0.0)
+        .assert_minimum_failure(f32::from_bits(1));
+}
+
+#[test]
+fn nans_are_nan() {
+    monkey_test()
+        .with_generator(gens::f32::nan())
+        .assert_true(|f| f.is_nan());
+}
+
+#[test]
+fn shrinks_nan_payload_of_float_examples() {
+    let result = monkey_test()
+        .with_generator(gens::f64::nan())
+        .test_true(|f| f.is_sign_positive());
+
+    // NaNs never equal each other, so the bits of the NaN are compared.
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure, ..
+        } => assert_eq!(minimum_failure.to_bits(), 0xfff8_0000_0000_0000),
+        MonkeyResult::MonkeyOk() => panic!("Expecting property to fail."),
+    }
+}
+
+#[test]
+fn shrinks_nan_toward_nan_with_negative_sign_bit() {
+    monkey_test()
+        .with_generator(gens::f64::nan().map(f64::to_bits, f64::from_bits))
+        .test_true(|bits| f64::from_bits(bits).is_sign_positive())
+        .assert_minimum_failure(0xfff8_0000_0000_0000);
+}
+
+#[test]
+fn shrinks_nan_toward_signalling_nan_with_smallest_payload() {
+    monkey_test()
+        .with_generator(gens::f64::nan().map(f64::to_bits, f64::from_bits))
+        .test_true(|bits| bits & 0x0008_0000_0000_0000 != 0)
+        .assert_minimum_failure(0x7ff0_0000_0000_0001);
+}
+
+#[test]
+fn finds_value_just_below_one_half_rounding_wrongly() {
+    monkey_test()
+        .with_generator(gens::f64::near(&[0.5]))
+        .test_true(|x| (x + 0.5).floor() == x.round())
+        .assert_minimum_failure(0.49999999999999994);
+}
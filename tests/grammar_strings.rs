@@ -0,0 +1,72 @@
+//! These tests shows how to generate structured text from a context-free
+//! grammar, for testing parsers and similar.
+
+use monkey_test::gens::grammar::Grammar;
+use monkey_test::*;
+
+fn expressions() -> Grammar {
+    Grammar::from_bnf(
+        r#"
+        <expr> ::= <term> { ( "+" | "-" ) <term> }
+        <term> ::= <num> | "(" <expr> ")"
+        <num>  ::= "0" | [ "-" ] <digit> <digit>*
+        <digit> ::= "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9"
+        "#,
+    )
+}
+
+/// Counts parentheses of expression, failing on unbalanced parentheses.
+fn max_nesting(expr: &str) -> Result<usize, String> {
+    let mut depth = 0usize;
+    let mut max = 0;
+    for c in expr.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.checked_sub(1).ok_or("unbalanced")?;
+            }
+            _ => {}
+        }
+        max = max.max(depth);
+    }
+    if depth == 0 {
+        Ok(max)
+    } else {
+        Err("unbalanced".into())
+    }
+}
+
+#[test]
+fn generated_expressions_have_balanced_parentheses() {
+    monkey_test()
+        .with_generator(expressions().strings())
+        .assert_true(|expr| max_nesting(&expr).is_ok());
+}
+
+#[test]
+fn shrinks_expression_toward_shortest_expansions() {
+    monkey_test()
+        .with_generator(expressions().strings())
+        .test_true(|expr| !expr.starts_with("(("))
+        .assert_minimum_failure("((0))".to_string());
+}
+
+#[test]
+fn shrinks_tokens_toward_shortest_expansions() {
+    monkey_test()
+        .with_generator(expressions().tokens())
+        .test_true(|tokens| tokens[0] != "(")
+        .assert_minimum_failure(vec![
+            "(".to_string(),
+            "0".to_string(),
+            ")".to_string(),
+        ]);
+}
+
+#[test]
+fn example_size_bounds_depth_of_nesting() {
+    monkey_test()
+        .with_example_size(0..=3)
+        .with_generator(expressions().strings())
+        .assert_true(|expr| max_nesting(&expr).unwrap() <= 1);
+}
@@ -0,0 +1,30 @@
+//! These tests shows how to find overflow bugs around bit pattern edge cases,
+//! that randomly distributed integers almost never hit.
+
+use monkey_test::*;
+
+/// Buggy range check, failing for values just above the range of `i32`.
+fn fits_in_i32(value: i64) -> bool {
+    value <= i32::MAX as i64 || value > i32::MAX as i64 + 16
+}
+
+// Both tests use the same seed and example count, for a fair comparison.
+
+#[test]
+fn finds_value_just_above_narrower_type() {
+    monkey_test()
+        .with_seed(1337)
+        .with_example_count(2000)
+        .with_generator(gens::i64::edge_biased(-(1 << 40)..(1 << 40), 1, 3))
+        .test_true(fits_in_i32)
+        .assert_minimum_failure(i32::MAX as i64 + 1);
+}
+
+#[test]
+fn random_values_do_not_find_value_just_above_narrower_type() {
+    monkey_test()
+        .with_seed(1337)
+        .with_example_count(2000)
+        .with_generator(gens::i64::ranged(-(1 << 40)..(1 << 40)))
+        .assert_true(fits_in_i32);
+}
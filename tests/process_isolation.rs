@@ -0,0 +1,182 @@
+//! These tests shows how crashes that can not be caught as panics, like
+//! aborts and stack overflows, can be found and shrunk by evaluating each
+//! example in a separate child process.
+
+use monkey_test::*;
+
+fn reason<E>(result: &MonkeyResult<E>) -> &str {
+    match result {
+        MonkeyResult::MonkeyOk() => panic!("Expecting failure."),
+        MonkeyResult::MonkeyErr { reason, .. } => reason,
+    }
+}
+
+fn depth(n: u64) -> u64 {
+    if n == 0 {
+        0
+    } else {
+        1 + depth(std::hint::black_box(n - 1))
+    }
+}
+
+#[test]
+fn abort_is_shrunk_like_any_failure() {
+    let result = monkey_test()
+        .with_process_isolation()
+        .with_example_count(20)
+        .with_generator(gens::u8::ranged(20..))
+        .with_shrinker(shrinks::int_to_zero())
+        .test_no_panic(|n| {
+            if n >= 10 {
+                std::process::abort()
+            }
+        });
+
+    result.assert_minimum_failure(10);
+    assert!(
+        reason(&result).starts_with("Expecting no crash, but child process"),
+        "got {}",
+        reason(&result)
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn abort_is_presented_as_signal() {
+    let result = monkey_test()
+        .with_process_isolation()
+        .with_example_count(1)
+        .with_generator(gens::fixed::constant(1u8))
+        .test_no_panic(|_| std::process::abort());
+
+    assert_eq!(
+        reason(&result),
+        "Expecting no crash, but child process was killed by signal 6 \
+        (SIGABRT)."
+    );
+}
+
+#[test]
+fn stack_overflow_is_a_failure() {
+    let result = monkey_test()
+        .with_process_isolation()
+        .with_example_count(1)
+        .with_generator(gens::fixed::constant(u64::MAX))
+        .test_true(|n| depth(n) == n);
+
+    assert!(
+        reason(&result).contains("stack overflow"),
+        "got {}",
+        reason(&result)
+    );
+}
+
+#[test]
+fn ordinary_failure_reason_is_kept() {
+    monkey_test()
+        .with_process_isolation()
+        .with_example_count(20)
+        .with_generator(gens::u8::ranged(20..))
+        .with_shrinker(shrinks::int_to_zero())
+        .test_eq(|n| n.min(10), |n| n)
+        .assert_minimum_failure(11);
+}
+
+#[test]
+fn never_finishing_example_is_killed_on_timeout() {
+    let timeout = std::time::Duration::from_secs(1);
+
+    let result = monkey_test()
+        .with_process_isolation()
+        .with_example_timeout(timeout)
+        .with_generator(gens::fixed::constant(10u8))
+        .with_shrinker(shrinks::int_to_zero())
+        .test_no_panic(|n| {
+            if n >= 10 {
+                loop {
+                    std::thread::sleep(std::time::Duration::from_millis(10));
+                }
+            }
+        });
+
+    result.assert_minimum_failure(10);
+    assert_eq!(
+        reason(&result),
+        format!("Expecting no timeout, but timed out after {timeout:?}.")
+    );
+}
+
+/// Mixed generators and generators remembering how examples were generated
+/// give the same examples and shrink candidates in the child processes, so
+/// that the same minimum failure is found as without process isolation.
+#[test]
+fn isolated_shrinking_finds_same_failure_as_not_isolated() {
+    fn generator() -> BoxGen<String> {
+        gens::mix_evenly(&[
+            gens::string::from_regex("[a-c]{2,8}-[0-9]{1,3}"),
+            gens::string::alpha_numeric(),
+        ])
+    }
+    fn conf() -> Conf {
+        monkey_test().with_seed(1234).with_example_count(20)
+    }
+    fn prop(s: String) -> bool {
+        !s.contains('-')
+    }
+
+    let isolated = conf()
+        .with_process_isolation()
+        .with_generator(generator())
+        .test_true(prop);
+    let not_isolated = conf().with_generator(generator()).test_true(prop);
+
+    assert_eq!(isolated, not_isolated);
+}
+
+#[test]
+fn explanation_of_failure_reason_is_kept() {
+    let result = monkey_test()
+        .with_process_isolation()
+        .with_example_count(20)
+        .with_generator(gens::u8::ranged(1..10))
+        .test_eq(|n| vec![0, n], |_| vec![0, 0]);
+
+    match result {
+        MonkeyResult::MonkeyErr { explanation, .. } => assert!(
+            explanation.starts_with("\n\nDiffering paths: [1]\n"),
+            "got {explanation:?}"
+        ),
+        other => panic!("{other:?} is unexpected"),
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "Only one process isolated property is supported in each test."
+)]
+fn several_isolated_properties_in_same_test_are_rejected() {
+    let conf = monkey_test()
+        .with_process_isolation()
+        .with_example_count(10);
+
+    conf.with_generator(gens::u8::ranged(..5))
+        .assert_true(|n| n < 5);
+
+    conf.with_generator(gens::u8::ranged(5..))
+        .assert_true(|n| n >= 5);
+}
+
+#[test]
+fn properties_not_isolated_may_precede_isolated_property() {
+    monkey_test()
+        .with_generator(gens::u8::ranged(5..))
+        .with_shrinker(shrinks::int_to_zero())
+        .test_true(|n| n < 5)
+        .assert_minimum_failure(5);
+
+    monkey_test()
+        .with_process_isolation()
+        .with_example_count(10)
+        .with_generator(gens::u8::ranged(..5))
+        .assert_true(|n| n < 5);
+}
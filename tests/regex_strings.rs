@@ -0,0 +1,68 @@
+//! These tests shows how to generate strings matching a regular expression,
+//! and that shrunk strings still match it.
+
+use monkey_test::*;
+use std::time::Duration;
+use std::time::Instant;
+
+fn emails() -> BoxGen<String> {
+    gens::string::from_regex("[a-z]{3,8}@[a-z]+\\.(com|org)")
+}
+
+#[test]
+fn generated_strings_match_regular_expression() {
+    monkey_test().with_generator(emails()).assert_true(|email| {
+        let (user, domain) = email.split_once('@').unwrap();
+        (3..=8).contains(&user.len())
+            && user.chars().all(|c| c.is_ascii_lowercase())
+            && (domain.ends_with(".com") || domain.ends_with(".org"))
+    });
+}
+
+#[test]
+fn shrinking_keeps_strings_matching_regular_expression() {
+    monkey_test()
+        .with_generator(emails())
+        .test_true(|email| !email.ends_with(".org"))
+        .assert_minimum_failure("aaa@a.org".to_string());
+}
+
+#[test]
+fn shrinking_dates_toward_earliest_date_with_the_same_day() {
+    let dates = gens::string::from_regex(
+        r"20[0-9]{2}-(0[1-9]|1[0-2])-(0[1-9]|[12][0-9]|3[01])",
+    );
+
+    monkey_test()
+        .with_generator(dates)
+        .test_true(|date| !date.ends_with("-31"))
+        .assert_minimum_failure("2000-01-31".to_string());
+}
+
+/// Ambiguous regular expressions, matching the same string in many ways,
+/// should not make shrinking slow.
+#[test]
+fn shrinking_is_fast_also_for_ambiguous_regular_expressions() {
+    let start = Instant::now();
+
+    monkey_test()
+        .with_generator(gens::string::from_regex("(a|a)*"))
+        .test_true(|text| text.len() < 20)
+        .assert_minimum_failure("a".repeat(20));
+
+    monkey_test()
+        .with_generator(gens::string::from_regex(r"(\d+ ?)*"))
+        .test_true(|text| !text.contains('7'))
+        .assert_minimum_failure("7".to_string());
+
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+#[should_panic(
+    expected = "Invalid regular expression \"[a-z\": unclosed character \
+    class at position 0"
+)]
+fn invalid_regular_expression_panics() {
+    gens::string::from_regex("[a-z");
+}
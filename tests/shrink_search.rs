@@ -0,0 +1,119 @@
+//! These tests shows how the search for a minimum failure can avoid evaluating
+//! the same example twice and how it can get out of local minimums.
+
+use monkey_test::*;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+
+/// Shrinker only taking one step at a time toward zero.
+fn one_step_down() -> BoxShrink<u32> {
+    shrinks::from_fn(|n: u32| n.checked_sub(1).into_iter())
+}
+
+#[test]
+fn already_tried_candidates_are_not_evaluated_again() {
+    static CALLS: AtomicU32 = AtomicU32::new(0);
+
+    monkey_test()
+        .with_example_count(1)
+        .with_generator(gens::fixed::constant(10u32))
+        .with_shrinker(shrinks::from_fn(|_| [5, 5, 5, 5].into_iter()))
+        .with_shrink_memoization()
+        .test_true(|n| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            n < 10
+        })
+        .assert_minimum_failure(10);
+
+    // One call for the generated example and one for the shrink candidate.
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn tried_candidates_are_evaluated_again_without_memoization() {
+    static CALLS: AtomicU32 = AtomicU32::new(0);
+
+    monkey_test()
+        .with_example_count(1)
+        .with_generator(gens::fixed::constant(10u32))
+        .with_shrinker(shrinks::from_fn(|_| [5, 5, 5, 5].into_iter()))
+        .test_true(|n| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            n < 10
+        })
+        .assert_minimum_failure(10);
+
+    // One call for the generated example and four for the shrink candidates.
+    // There is no final minimality check, since the effort was not exhausted.
+    assert_eq!(CALLS.load(Ordering::SeqCst), 5);
+}
+
+#[test]
+fn greedy_shrinking_gets_stuck_in_local_minimum() {
+    monkey_test()
+        .with_example_count(1)
+        .with_generator(gens::fixed::constant(100u32))
+        .with_shrinker(one_step_down())
+        .test_true(|n| n != 100 && n != 50)
+        .assert_minimum_failure(100);
+}
+
+#[test]
+fn backtracking_gets_out_of_local_minimum() {
+    monkey_test()
+        .with_example_count(1)
+        .with_shrink_backtracking(60, 1)
+        .with_generator(gens::fixed::constant(100u32))
+        .with_shrinker(one_step_down())
+        .test_true(|n| n != 100 && n != 50)
+        .assert_minimum_failure(50);
+}
+
+#[test]
+fn backtracking_is_bounded_by_depth() {
+    monkey_test()
+        .with_example_count(1)
+        .with_shrink_backtracking(40, 1)
+        .with_generator(gens::fixed::constant(100u32))
+        .with_shrinker(one_step_down())
+        .test_true(|n| n != 100 && n != 50)
+        .assert_minimum_failure(100);
+}
+
+#[test]
+fn minimality_check_continues_after_effort_is_exhausted() {
+    // Each example has more passing candidates than the shrink effort, before
+    // the failing candidate one step down.
+    let shrinker = shrinks::from_fn(|n: u32| {
+        (0..10_000)
+            .map(move |i| n + 1000 + i)
+            .chain(n.checked_sub(1))
+    });
+
+    monkey_test()
+        .with_example_count(1)
+        .with_generator(gens::fixed::constant(5u32))
+        .with_shrinker(shrinker)
+        .with_shrink_memoization()
+        .test_true(|n| n > 5)
+        .assert_minimum_failure(0);
+}
+
+#[test]
+fn minimality_check_continues_after_draws_are_exhausted() {
+    // Each example has more repetitions of an already tried candidate than
+    // half the draws allowed, before the failing candidate one step down.
+    let shrinker = shrinks::from_fn(|n: u32| {
+        std::iter::repeat(n + 1)
+            .take(600_000)
+            .chain(n.checked_sub(1))
+    });
+
+    monkey_test()
+        .with_example_count(1)
+        .with_generator(gens::fixed::constant(2u32))
+        .with_shrinker(shrinker)
+        .with_shrink_memoization()
+        .test_true(|n| n > 2)
+        .assert_minimum_failure(0);
+}
@@ -0,0 +1,123 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Binary Heap challenge
+//!
+//! This tests the (wrong) property that popping all values from a binary
+//! heap, using a faulty merge that ignores the heap order, gives the values
+//! in sorted order. It validates that a library can shrink recursive data
+//! structures with invariants.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/binheap.md
+
+use monkey_test::*;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Heap {
+    Empty,
+    Node(u32, Box<Heap>, Box<Heap>),
+}
+
+use Heap::Empty;
+use Heap::Node;
+
+fn node(value: u32, left: Heap, right: Heap) -> Heap {
+    Node(value, Box::new(left), Box::new(right))
+}
+
+fn leaf(value: u32) -> Heap {
+    node(value, Empty, Empty)
+}
+
+/// Faulty merge of two heaps, not comparing values at all.
+fn wrong_merge(h1: Heap, h2: Heap) -> Heap {
+    match (h1, h2) {
+        (Empty, h) | (h, Empty) => h,
+        (Node(v1, l1, r1), h2) => node(v1, *l1, wrong_merge(*r1, h2)),
+    }
+}
+
+/// Build a complete heap by inserting values in an array based heap, one by
+/// one, moving each value up as long as it is smaller than its parent.
+fn heapify(values: Vec<u32>) -> Heap {
+    let mut array = vec![];
+    for value in values {
+        array.push(value);
+        let mut i = array.len() - 1;
+        while i > 0 && array[(i - 1) / 2] > array[i] {
+            array.swap((i - 1) / 2, i);
+            i = (i - 1) / 2;
+        }
+    }
+    from_array(&array, 0)
+}
+
+fn from_array(array: &[u32], i: usize) -> Heap {
+    match array.get(i) {
+        None => Empty,
+        Some(&value) => node(
+            value,
+            from_array(array, 2 * i + 1),
+            from_array(array, 2 * i + 2),
+        ),
+    }
+}
+
+/// Values in level order, which is a list that gives the same heap again
+/// when passed to [heapify].
+fn to_list(heap: Heap) -> Vec<u32> {
+    let mut list = vec![];
+    let mut level = vec![heap];
+    while !level.is_empty() {
+        let mut next_level = vec![];
+        for heap in level {
+            if let Node(value, left, right) = heap {
+                list.push(value);
+                next_level.push(*left);
+                next_level.push(*right);
+            }
+        }
+        level = next_level;
+    }
+    list
+}
+
+fn to_sorted_list(mut heap: Heap) -> Vec<u32> {
+    let mut list = vec![];
+    while let Node(value, left, right) = heap {
+        list.push(value);
+        heap = wrong_merge(*left, *right);
+    }
+    list
+}
+
+/// Heaps built by inserting generated values one by one. Heaps are shrunk by
+/// shrinking their values in level order.
+fn heaps() -> BoxGen<Heap> {
+    gens::vec::any(gens::u32::any()).map(heapify, to_list)
+}
+
+/// Should shrink down to the heap `(0, 1, 0)`, of three nodes.
+#[test]
+fn test_binheap() {
+    let result = monkey_test().with_generator(heaps()).test_true(|heap| {
+        let mut expected = to_list(heap.clone());
+        expected.sort();
+        to_sorted_list(heap) == expected
+    });
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, node(0, leaf(1), leaf(0)));
+            assert!(shrink_count <= 80, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
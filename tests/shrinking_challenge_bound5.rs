@@ -0,0 +1,69 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Bound5 challenge
+//!
+//! This tests the (wrong) property that, given five lists of 16 bit integers
+//! each summing to less than 256, the sum of all elements is less than
+//! 5 * 256. The property fails due to overflow. It validates that a library
+//! can shrink several filtered lists at once.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/bound5.md
+
+use monkey_test::*;
+
+fn wrapping_sum(list: &[i16]) -> i16 {
+    list.iter().fold(0, |sum, e| sum.wrapping_add(*e))
+}
+
+fn is_bounded(list: &[i16]) -> bool {
+    wrapping_sum(list) < 256
+}
+
+/// Lists within bound, shrunk keeping the sum of their elements where
+/// possible, so that shrink candidates mostly stay within bound.
+fn bounded_list() -> BoxGen<Vec<i16>> {
+    gens::vec::any(gens::i16::any())
+        .with_shrinker(shrinks::vec::sum_preserving(shrinks::int_to_zero()))
+        .filter(|list| is_bounded(list))
+}
+
+/// Should shrink down to single elements in some of the lists, with the other
+/// lists empty. Each list is within bound, but the sum of the elements
+/// overflows by exactly one, so that shrinking any element makes the sum fit.
+///
+/// Most often two elements remain. Reaching the smallest failure, -1 and
+/// -32768, from three or more elements would require moving values between
+/// lists, which shrinking does not do.
+#[test]
+fn test_bound5() {
+    let result = monkey_test()
+        .with_generator(bounded_list().zip_5(
+            bounded_list(),
+            bounded_list(),
+            bounded_list(),
+            bounded_list(),
+        ))
+        .test_true(|(a, b, c, d, e)| {
+            let sums = [a, b, c, d, e].map(|list| wrapping_sum(&list));
+            wrapping_sum(&sums) < 5 * 256
+        });
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure: (a, b, c, d, e),
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            let lists = [a, b, c, d, e];
+            assert!(lists.iter().all(|list| list.len() <= 1), "{lists:?}");
+            let sum: i32 = lists.concat().iter().map(|&e| e as i32).sum();
+            assert_eq!(sum, i16::MIN as i32 - 1, "lists {lists:?}");
+            assert!(shrink_count <= 40, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
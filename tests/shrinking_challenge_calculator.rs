@@ -0,0 +1,145 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Calculator challenge
+//!
+//! This tests the (wrong) property that evaluating an arithmetic expression
+//! never divides by zero, as long as no division has a literal zero as
+//! divisor. It validates that a library can shrink recursive data structures
+//! using a custom generator and shrinker.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/calculator.md
+
+use monkey_test::*;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expr {
+    Int(i32),
+    Add(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+}
+
+use Expr::Add;
+use Expr::Div;
+use Expr::Int;
+
+fn add(left: Expr, right: Expr) -> Expr {
+    Add(Box::new(left), Box::new(right))
+}
+
+fn div(left: Expr, right: Expr) -> Expr {
+    Div(Box::new(left), Box::new(right))
+}
+
+/// True if no division has a literal zero as divisor.
+fn no_literal_div_by_zero(expr: &Expr) -> bool {
+    match expr {
+        Int(_) => true,
+        Add(left, right) => {
+            no_literal_div_by_zero(left) && no_literal_div_by_zero(right)
+        }
+        Div(_, right) if **right == Int(0) => false,
+        Div(left, right) => {
+            no_literal_div_by_zero(left) && no_literal_div_by_zero(right)
+        }
+    }
+}
+
+/// Evaluate expression, returning `None` on division by zero.
+fn evaluate(expr: &Expr) -> Option<i32> {
+    match expr {
+        Int(n) => Some(*n),
+        Add(left, right) => {
+            Some(evaluate(left)?.wrapping_add(evaluate(right)?))
+        }
+        Div(left, right) => evaluate(left)?.checked_div(evaluate(right)?),
+    }
+}
+
+/// Random expression, using `choices` to pick type of each expression and
+/// `ints` for values of integer literals.
+fn random_expr(
+    choices: &mut BoxIter<u8>,
+    ints: &mut BoxIter<i32>,
+    depth: u8,
+) -> Expr {
+    let choice = choices.next().expect("choices should be infinite");
+
+    if depth == 0 || choice < 128 {
+        Int(ints.next().expect("ints should be infinite"))
+    } else if choice < 192 {
+        add(
+            random_expr(choices, ints, depth - 1),
+            random_expr(choices, ints, depth - 1),
+        )
+    } else {
+        div(
+            random_expr(choices, ints, depth - 1),
+            random_expr(choices, ints, depth - 1),
+        )
+    }
+}
+
+fn exprs() -> BoxGen<Expr> {
+    gens::from_fn(|seed, size| {
+        let mut choices =
+            gens::u8::completely_random(..).examples(seed, size.clone());
+        let mut ints = gens::i32::any().examples(seed + 1, size);
+
+        std::iter::repeat_with(move || random_expr(&mut choices, &mut ints, 5))
+    })
+    .with_shrinker(shrinks::from_fn(smaller_exprs))
+}
+
+/// Shrink candidates of expression. Subexpressions are tried first, then
+/// integer literals are shrunk and finally subexpressions are shrunk in place.
+fn smaller_exprs(expr: Expr) -> BoxIter<Expr> {
+    match expr {
+        Int(n) => Box::new(shrinks::int_to_zero().candidates(n).map(Int)),
+        Add(left, right) => binary_candidates(*left, *right, add),
+        Div(left, right) => binary_candidates(*left, *right, div),
+    }
+}
+
+fn binary_candidates(
+    left: Expr,
+    right: Expr,
+    combine: fn(Expr, Expr) -> Expr,
+) -> BoxIter<Expr> {
+    let (l, r) = (left.clone(), right.clone());
+    let smaller_left = smaller_exprs(left.clone())
+        .map(move |smaller| combine(smaller, r.clone()));
+    let smaller_right = smaller_exprs(right.clone())
+        .map(move |smaller| combine(l.clone(), smaller));
+
+    Box::new(
+        vec![left, right]
+            .into_iter()
+            .chain(smaller_left)
+            .chain(smaller_right),
+    )
+}
+
+/// Should shrink down to an expression of five nodes, dividing zero by an
+/// expression evaluating to zero, like `Div(0, Div(0, 1))`.
+#[test]
+fn test_calculator() {
+    let result = monkey_test().with_generator(exprs()).test_true(|expr| {
+        !no_literal_div_by_zero(&expr) || evaluate(&expr).is_some()
+    });
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, div(Int(0), div(Int(0), Int(1))));
+            assert!(shrink_count <= 50, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
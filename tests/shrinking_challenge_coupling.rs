@@ -0,0 +1,52 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Coupling challenge
+//!
+//! This tests the (wrong) property that a list of indexes into the list
+//! itself never contains a 2-cycle, where the element at index i is j and
+//! the element at index j is i. It validates that a library can shrink a
+//! list where the elements depend on the list length.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/coupling.md
+
+use monkey_test::*;
+
+/// Lists of indexes into the list itself.
+fn index_lists() -> BoxGen<Vec<usize>> {
+    gens::vec::any(gens::usize::ranged(..10)).map(
+        |list| {
+            let len = list.len();
+            list.into_iter().map(|index| index % len).collect()
+        },
+        |list| list,
+    )
+}
+
+/// Should shrink down to two element vector [1, 0].
+#[test]
+fn test_coupling() {
+    let result =
+        monkey_test()
+            .with_generator(index_lists())
+            .test_true(|list| {
+                list.iter()
+                    .enumerate()
+                    .all(|(i, &j)| i == j || list[j] != i)
+            });
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, vec![1, 0]);
+            assert!(shrink_count <= 20, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
@@ -0,0 +1,57 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Deletion challenge
+//!
+//! This tests the (wrong) property that removing the first occurrence of a
+//! list element, picked by index, leaves no occurrence of that value in the
+//! list. It validates that a library can shrink a list together with an index
+//! into it.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/deletion.md
+
+use monkey_test::*;
+
+/// Non-empty lists with an index into the list.
+fn lists_with_index() -> BoxGen<(Vec<i32>, usize)> {
+    gens::zip(
+        gens::vec::any(gens::i32::any()).filter(|list| !list.is_empty()),
+        gens::usize::any(),
+    )
+    .map(
+        |(list, index)| {
+            let len = list.len();
+            (list, index % len)
+        },
+        |pair| pair,
+    )
+}
+
+/// Should shrink down to ([0, 0], 0).
+#[test]
+fn test_deletion() {
+    let result = monkey_test().with_generator(lists_with_index()).test_true(
+        |(mut list, index)| {
+            let value = list[index];
+            if let Some(first) = list.iter().position(|&e| e == value) {
+                list.remove(first);
+            }
+            !list.contains(&value)
+        },
+    );
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, (vec![0, 0], 0));
+            assert!(shrink_count <= 30, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
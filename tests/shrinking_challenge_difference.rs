@@ -0,0 +1,86 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Difference challenges
+//!
+//! These test three (wrong) properties about the difference between two
+//! positive integers, given that the first integer is at least 10. They
+//! validate that a library can shrink two values that are coupled by the
+//! property.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/difference.md
+
+use monkey_test::*;
+
+/// Pairs of positive integers, shifted down together when shrinking, since
+/// shrinking either integer alone changes the difference. Many examples are
+/// used, since equal or close values are rare.
+fn positive_pairs() -> ConfAndGen<(u32, u32)> {
+    monkey_test().with_example_count(10_000).with_generator(
+        gens::zip(gens::u32::ranged(1..=1000), gens::u32::ranged(1..=1000))
+            .with_shrinker(shrinks::int_pair_in_range(1, 1000)),
+    )
+}
+
+/// Should shrink down to (10, 10).
+#[test]
+fn test_difference_must_not_be_zero() {
+    let result = positive_pairs().test_true(|(x, y)| x < 10 || x != y);
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, (10, 10));
+            assert!(shrink_count <= 10, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
+
+/// Should shrink down to (10, 6).
+#[test]
+fn test_difference_must_not_be_small() {
+    let result = positive_pairs()
+        .test_true(|(x, y)| x < 10 || !(1..=4).contains(&x.abs_diff(y)));
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, (10, 6));
+            assert!(shrink_count <= 20, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
+
+/// Should shrink down to (10, 9).
+#[test]
+fn test_difference_must_not_be_one() {
+    let result =
+        positive_pairs().test_true(|(x, y)| x < 10 || x.abs_diff(y) != 1);
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, (10, 9));
+            assert!(shrink_count <= 20, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
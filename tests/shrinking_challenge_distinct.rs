@@ -0,0 +1,37 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Distinct challenge
+//!
+//! This tests the (wrong) property that a list of integers contains fewer
+//! than three distinct values. It validates that a library can shrink the
+//! elements of a list toward values that differ as little as possible.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/distinct.md
+
+use monkey_test::*;
+use std::collections::HashSet;
+
+/// Should shrink down to three element vector [-1, 0, 1].
+#[test]
+fn test_distinct() {
+    let result = monkey_test()
+        .with_generator(gens::vec::any(gens::i32::any()))
+        .with_shrinker(shrinks::vec::normalizing(gens::i32::any().shrinker()))
+        .test_true(|list| list.iter().collect::<HashSet<_>>().len() < 3);
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, vec![-1, 0, 1]);
+            assert!(shrink_count <= 100, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
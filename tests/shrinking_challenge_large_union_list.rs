@@ -0,0 +1,42 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Large Union List challenge
+//!
+//! This tests the (wrong) property that the union of a list of lists of
+//! integers has at most four distinct values. It validates that a library can
+//! shrink nested lists, both in structure and in values.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/large_union_list.md
+
+use monkey_test::*;
+use std::collections::HashSet;
+
+/// Should shrink down to a single list of five distinct values
+/// [[-2, -1, 0, 1, 2]].
+#[test]
+fn test_large_union_list() {
+    let result = monkey_test()
+        .with_generator(gens::vec::any(gens::vec::any(gens::i32::any())))
+        .with_shrinker(shrinks::vec::normalizing(shrinks::vec::normalizing(
+            gens::i32::any().shrinker(),
+        )))
+        .test_true(|lists| {
+            lists.iter().flatten().collect::<HashSet<_>>().len() <= 4
+        });
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, vec![vec![-2, -1, 0, 1, 2]]);
+            assert!(shrink_count <= 200, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
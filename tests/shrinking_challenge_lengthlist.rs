@@ -0,0 +1,37 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Length List challenge
+//!
+//! This tests the (wrong) property that the maximum of a non-empty list of
+//! integers below 1000 is less than 900. It validates that a library can
+//! shrink away elements not involved in the failure, also when the list
+//! must not be empty.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/lengthlist.md
+
+use monkey_test::*;
+
+/// Should shrink down to single element vector [900].
+#[test]
+fn test_lengthlist() {
+    let result = monkey_test()
+        .with_example_size(1..=100)
+        .with_generator(gens::vec::any(gens::u16::ranged(..1000)))
+        .test_true(|list| list.iter().max().map_or(true, |&max| max < 900));
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, vec![900]);
+            assert!(shrink_count <= 20, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
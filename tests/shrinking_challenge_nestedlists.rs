@@ -0,0 +1,35 @@
+//! This is the MonkeyTest implementations of a
+//! [Shrinking Challenge](https://github.com/jlink/shrinking-challenge).
+//!
+//! # About the Nested Lists challenge
+//!
+//! This tests the (wrong) property that the total length of a list of lists
+//! of integers is at most 10. It validates that a library can move elements
+//! between nested lists, merging them into one.
+//!
+//! For details on this challenge, see
+//! https://github.com/jlink/shrinking-challenge/blob/main/challenges/nestedlists.md
+
+use monkey_test::*;
+
+/// Should shrink down to a single list of 11 zeros.
+#[test]
+fn test_nestedlists() {
+    let result = monkey_test()
+        .with_generator(gens::vec::any(gens::vec::any(gens::i32::any())))
+        .test_true(|lists| lists.iter().map(Vec::len).sum::<usize>() <= 10);
+
+    match result {
+        MonkeyResult::MonkeyErr {
+            minimum_failure,
+            shrink_count,
+            ..
+        } => {
+            println!("shrink count: {:?}", shrink_count);
+
+            assert_eq!(minimum_failure, vec![vec![0; 11]]);
+            assert!(shrink_count <= 50, "shrink count {shrink_count}");
+        }
+        other => panic!("{:?} is unexpected", other),
+    }
+}
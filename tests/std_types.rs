@@ -0,0 +1,100 @@
+//! These tests shows how to generate values of types from the standard
+//! library, like durations, points in time, network addresses and paths.
+
+use monkey_test::*;
+use std::cmp::Ordering;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::UNIX_EPOCH;
+
+#[test]
+fn shrinks_duration_toward_zero() {
+    monkey_test()
+        .with_generator(gens::duration::any())
+        .test_true(|d| d < Duration::from_secs(1))
+        .assert_minimum_failure(Duration::from_secs(1));
+}
+
+#[test]
+fn ranged_durations_are_in_range() {
+    monkey_test()
+        .with_generator(gens::duration::ranged(
+            Duration::from_millis(10)..Duration::from_millis(20),
+        ))
+        .assert_true(|d| {
+            Duration::from_millis(10) <= d && d < Duration::from_millis(20)
+        });
+}
+
+#[test]
+fn shrinks_system_time_toward_unix_epoch() {
+    monkey_test()
+        .with_generator(gens::system_time::any())
+        .test_true(|t| t < UNIX_EPOCH + Duration::from_secs(24 * 60 * 60))
+        .assert_minimum_failure(UNIX_EPOCH + Duration::from_secs(24 * 60 * 60));
+}
+
+#[test]
+fn shrinks_ipv4_address_toward_unspecified_address() {
+    monkey_test()
+        .with_generator(gens::net::ipv4())
+        .test_true(|ip| ip.octets()[0] < 100)
+        .assert_minimum_failure(Ipv4Addr::new(100, 0, 0, 0));
+}
+
+#[test]
+fn shrinks_ipv6_address_toward_unspecified_address() {
+    monkey_test()
+        .with_generator(gens::net::ip())
+        .test_true(|ip| ip.is_ipv4())
+        .assert_minimum_failure(IpAddr::V6(Ipv6Addr::UNSPECIFIED));
+}
+
+#[test]
+fn shrinks_socket_address_toward_port_zero() {
+    monkey_test()
+        .with_generator(gens::net::socket_addr())
+        .test_true(|addr| addr.port() < 1024)
+        .assert_minimum_failure(SocketAddr::new(
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            1024,
+        ));
+}
+
+#[test]
+fn shrinks_path_toward_fewer_components() {
+    monkey_test()
+        .with_generator(gens::path::any())
+        .test_true(|path| !path.to_string_lossy().contains(".."))
+        .assert_minimum_failure(PathBuf::from(".."));
+}
+
+#[test]
+fn generates_absolute_paths() {
+    monkey_test()
+        .with_generator(gens::path::any())
+        .test_true(|path| path.is_relative())
+        .assert_minimum_failure(PathBuf::from("/"));
+}
+
+#[test]
+fn shrinks_ordering_toward_equal() {
+    for ordering in [Ordering::Less, Ordering::Greater] {
+        let result = monkey_test()
+            .with_generator(gens::fixed::constant(ordering))
+            .with_shrinker(gens::ordering::any().shrinker())
+            .test_true(|_| false);
+
+        result.assert_minimum_failure(Ordering::Equal);
+        if let MonkeyResult::MonkeyErr {
+            original_failure, ..
+        } = result
+        {
+            assert_eq!(original_failure, ordering);
+        }
+    }
+}
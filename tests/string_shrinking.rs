@@ -0,0 +1,49 @@
+//! These tests shows that strings and chars shrink toward simple chars, like
+//! 'a', within the char class of the generator.
+
+use monkey_test::*;
+
+#[test]
+fn ascii_strings_shrink_toward_lower_case_a() {
+    monkey_test()
+        .with_generator(gens::string::ascii())
+        .test_true(|text| text.len() < 3)
+        .assert_minimum_failure("aaa".to_string());
+}
+
+#[test]
+fn unicode_strings_shrink_toward_lower_case_a() {
+    monkey_test()
+        .with_generator(gens::string::unicode())
+        .test_true(|text| text.chars().count() < 2)
+        .assert_minimum_failure("aa".to_string());
+}
+
+#[test]
+fn upper_case_strings_shrink_toward_upper_case_a() {
+    monkey_test()
+        .with_generator(gens::string::alpha_upper())
+        .test_true(|text| text.len() < 2)
+        .assert_minimum_failure("AA".to_string());
+}
+
+#[test]
+fn number_chars_shrink_toward_zero() {
+    monkey_test()
+        .with_generator(gens::char::number())
+        .test_true(|_| false)
+        .assert_minimum_failure('0');
+}
+
+#[test]
+fn printable_chars_shrink_toward_digits_and_space_after_letters() {
+    monkey_test()
+        .with_generator(gens::char::ascii_printable())
+        .test_true(|c| c.is_alphabetic())
+        .assert_minimum_failure('0');
+
+    monkey_test()
+        .with_generator(gens::char::ascii_printable())
+        .test_true(|c| c.is_alphanumeric())
+        .assert_minimum_failure(' ');
+}
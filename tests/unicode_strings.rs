@@ -0,0 +1,45 @@
+//! These tests shows how to generate strings full of unicode edge cases, and
+//! canonically equivalent strings, for testing text processing.
+
+use monkey_test::*;
+
+#[test]
+fn edge_cases_are_frequent_enough_to_be_found() {
+    monkey_test()
+        .with_generator(gens::string::unicode_edge_cases())
+        .test_true(|s| !s.contains('\u{202E}'))
+        .assert_minimum_failure("\u{202E}".to_string());
+}
+
+#[test]
+fn shrinks_toward_simplest_non_ascii_edge_case() {
+    monkey_test()
+        .with_generator(gens::string::unicode_edge_cases())
+        .test_true(|s| s.is_ascii())
+        .assert_minimum_failure("\u{300}".to_string());
+}
+
+#[test]
+fn emoji_sequences_are_kept_whole_while_shrinking() {
+    monkey_test()
+        .with_generator(gens::string::unicode_edge_cases())
+        .test_true(|s| !s.contains('\u{1F308}'))
+        .assert_minimum_failure(
+            "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}".to_string(),
+        );
+}
+
+#[test]
+fn nfd_strings_have_at_least_as_many_chars_as_nfc_strings() {
+    monkey_test()
+        .with_generator(gens::string::nfc_and_nfd())
+        .assert_true(|(nfc, nfd)| nfc.chars().count() <= nfd.chars().count());
+}
+
+#[test]
+fn shrinks_toward_simplest_differently_encoded_pair() {
+    monkey_test()
+        .with_generator(gens::string::nfc_and_nfd())
+        .test_true(|(nfc, nfd)| nfc == nfd)
+        .assert_minimum_failure(("\u{E9}".to_string(), "e\u{301}".to_string()));
+}
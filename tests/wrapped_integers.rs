@@ -0,0 +1,74 @@
+//! These tests shows how to generate non-zero integers and integers wrapped in
+//! `Wrapping` and `Saturating`.
+
+use monkey_test::*;
+use std::num::NonZeroU32;
+use std::num::Saturating;
+use std::num::Wrapping;
+
+#[test]
+fn shrinks_non_zero_integer_toward_one() {
+    monkey_test()
+        .with_generator(gens::nonzero::u32::any())
+        .test_true(|n| n.get() % 2 == 0)
+        .assert_minimum_failure(NonZeroU32::new(1).unwrap());
+}
+
+#[test]
+fn non_zero_signed_integers_are_never_zero_when_shrinking() {
+    monkey_test()
+        .with_generator(gens::nonzero::i8::any())
+        .test_true(|n| n.get() > 10)
+        .assert_minimum_failure(std::num::NonZeroI8::new(1).unwrap());
+}
+
+#[test]
+fn non_zero_integers_of_range_ending_at_zero_are_negative() {
+    monkey_test()
+        .with_generator(gens::nonzero::i8::ranged(-10..=0))
+        .assert_true(|n| n.get() < 0);
+}
+
+#[test]
+fn non_zero_integers_of_range_around_zero_have_both_signs() {
+    let values = gens::nonzero::i8::ranged(-1..=1)
+        .examples(1337, 0..=1000)
+        .take(100)
+        .map(|n| n.get())
+        .collect::<std::collections::BTreeSet<_>>();
+
+    assert_eq!(values, [-1, 1].into());
+}
+
+#[test]
+#[should_panic(expected = "Given bounds only contain zero.")]
+fn non_zero_integers_need_some_non_zero_value_in_range() {
+    gens::nonzero::u8::ranged(..=0);
+}
+
+#[test]
+fn finds_wrapping_overflow() {
+    // Seed is fixed, since only some seeds generate the one overflowing
+    // value u8::MAX within the examples tried.
+    monkey_test()
+        .with_seed(1337)
+        .with_generator(gens::wrapping::u8::any())
+        .test_true(|w| (w + Wrapping(1)).0 != 0)
+        .assert_minimum_failure(Wrapping(u8::MAX));
+}
+
+#[test]
+fn finds_saturation() {
+    monkey_test()
+        .with_generator(gens::saturating::u8::any())
+        .test_true(|s| (s + Saturating(100)).0 - s.0 == 100)
+        .assert_minimum_failure(Saturating(156));
+}
+
+#[test]
+fn shrinks_isize_like_other_integers() {
+    monkey_test()
+        .with_generator(gens::isize::any())
+        .test_true(|i| i > -5)
+        .assert_minimum_failure(-5);
+}